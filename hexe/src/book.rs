@@ -0,0 +1,237 @@
+//! [Polyglot][polyglot] opening book support.
+//!
+//! # Keys
+//!
+//! Entries in a Polyglot book are keyed by Polyglot's own Zobrist hash, which
+//! uses a fixed set of 781 published keys. These are **not** the same as the
+//! keys in [`zobrist::KEYS`], so the hash returned by
+//! [`Position::zobrist`] cannot be used to probe a Polyglot book. Keys must
+//! instead be computed with the Polyglot scheme.
+//!
+//! [polyglot]: http://hgm.nubati.net/book_format.html
+//! [`zobrist::KEYS`]: ../zobrist/static.KEYS.html
+//! [`Position::zobrist`]: ../position/struct.Position.html#method.zobrist
+
+use std::cmp::Ordering;
+use std::io::{self, Read};
+
+use core::piece::Promotion;
+use prelude::*;
+
+/// The number of bytes in each book entry.
+const ENTRY_LEN: usize = 16;
+
+/// A single move in an [`OpeningBook`](struct.OpeningBook.html).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Entry {
+    /// The Polyglot hash of the position.
+    pub key: u64,
+    /// The move in Polyglot's encoding.
+    pub raw_move: u16,
+    /// The relative weight of the move among those for the same position.
+    pub weight: u16,
+    /// Learning data, unused by most books.
+    pub learn: u32,
+}
+
+impl Entry {
+    /// Parses an entry from its big-endian binary representation.
+    fn from_bytes(b: &[u8]) -> Entry {
+        let be = |b: &[u8]| b.iter().fold(0u64, |acc, &x| acc << 8 | x as u64);
+        Entry {
+            key:      be(&b[0..8]),
+            raw_move: be(&b[8..10])  as u16,
+            weight:   be(&b[10..12]) as u16,
+            learn:    be(&b[12..16]) as u32,
+        }
+    }
+
+    /// Decodes the move of `self`.
+    ///
+    /// Polyglot writes castles as the king capturing its own rook, such as
+    /// `e1h1`, so those squares are always decoded as castles. En passant
+    /// captures cannot be told apart from normal pawn captures without a
+    /// position, so they are decoded as normal moves.
+    pub fn to_move(&self) -> Option<Move> {
+        let raw = self.raw_move;
+        let square = |bits: u16| {
+            Square::new(((bits & 7) as u8).into(), ((bits >> 3 & 7) as u8).into())
+        };
+        let dst = square(raw);
+        let src = square(raw >> 6);
+
+        let right = match (src, dst) {
+            (Square::E1, Square::H1) => Some(Right::WhiteKing),
+            (Square::E1, Square::A1) => Some(Right::WhiteQueen),
+            (Square::E8, Square::H8) => Some(Right::BlackKing),
+            (Square::E8, Square::A8) => Some(Right::BlackQueen),
+            _ => None,
+        };
+        if let Some(right) = right {
+            return Some(Move::castle(right));
+        }
+
+        match raw >> 12 & 7 {
+            0 => Some(Move::normal(src, dst)),
+            piece @ 1..=4 => {
+                let piece = Promotion::from_role(Role::from(piece as u8))?;
+                Move::try_promotion(src, dst, piece)
+            },
+            _ => None,
+        }
+    }
+}
+
+/// A [Polyglot][polyglot] opening book.
+///
+/// [polyglot]: http://hgm.nubati.net/book_format.html
+#[derive(Clone, Debug, Default)]
+pub struct OpeningBook {
+    /// Entries sorted by key.
+    entries: Vec<Entry>,
+}
+
+impl OpeningBook {
+    /// Reads a book in the Polyglot `.bin` format from `reader`.
+    ///
+    /// Each entry spans 16 bytes: an 8-byte key, a 2-byte move, a 2-byte
+    /// weight, and 4 bytes of learning data, all big-endian.
+    pub fn from_reader<R: Read>(mut reader: R) -> io::Result<OpeningBook> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        if bytes.len() % ENTRY_LEN != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "book length is not a multiple of the entry size",
+            ));
+        }
+
+        let mut entries: Vec<Entry> = bytes.chunks(ENTRY_LEN)
+                                           .map(Entry::from_bytes)
+                                           .collect();
+        // Books should already be sorted; this keeps lookups correct if not
+        entries.sort_by_key(|entry| entry.key);
+
+        Ok(OpeningBook { entries })
+    }
+
+    /// Returns the number of entries in `self`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether `self` has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns all entries for the Polyglot `key`.
+    pub fn entries(&self, key: u64) -> &[Entry] {
+        let start = self.entries.binary_search_by(|entry| {
+            if entry.key < key { Ordering::Less } else { Ordering::Greater }
+        }).unwrap_or_else(|i| i);
+
+        let len = self.entries[start..].iter()
+                                       .take_while(|entry| entry.key == key)
+                                       .count();
+        &self.entries[start..(start + len)]
+    }
+
+    /// Returns the move with the greatest weight for the Polyglot `key`, as
+    /// decoded by [`Entry::to_move`](struct.Entry.html#method.to_move).
+    ///
+    /// Ties are broken in favor of the entry that comes first in the book.
+    ///
+    /// The move is decoded without a position, so `e1h1`, `e1a1`, `e8h8`, and
+    /// `e8a8` are always returned as castles, even if the book meant a rook
+    /// or queen moving between those squares. Such a move can be detected by
+    /// checking it against the legal moves of the position, where a king
+    /// cannot castle from a square held by another piece.
+    pub fn probe(&self, key: u64) -> Option<Move> {
+        self.entries(key).iter().fold(None, |best: Option<&Entry>, entry| {
+            match best {
+                Some(best) if best.weight >= entry.weight => Some(best),
+                _ => Some(entry),
+            }
+        }).and_then(Entry::to_move)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The Polyglot key of the standard starting position.
+    const START: u64 = 0x463B_9618_1691_FC9C;
+
+    /// An arbitrary key for a position where White can castle.
+    const OTHER: u64 = 0x1234_5678_9ABC_DEF0;
+
+    fn entry(key: u64, raw_move: u16, weight: u16) -> [u8; ENTRY_LEN] {
+        let mut bytes = [0; ENTRY_LEN];
+        for (i, byte) in bytes[..8].iter_mut().enumerate() {
+            *byte = (key >> (56 - 8 * i)) as u8;
+        }
+        bytes[8]  = (raw_move >> 8) as u8;
+        bytes[9]  = raw_move as u8;
+        bytes[10] = (weight >> 8) as u8;
+        bytes[11] = weight as u8;
+        bytes
+    }
+
+    fn fixture() -> Vec<u8> {
+        let entries = [
+            entry(START,   0x031C, 10), // e2e4
+            entry(START,   0x02DB, 25), // d2d4
+            entry(START,   0x0195, 25), // g1f3
+            entry(OTHER,   0x0107, 7),  // e1h1
+        ];
+        let mut bytes = Vec::new();
+        for entry in entries.iter().rev() {
+            bytes.extend_from_slice(entry);
+        }
+        bytes
+    }
+
+    #[test]
+    fn probe() {
+        let book = OpeningBook::from_reader(&fixture()[..]).unwrap();
+        assert_eq!(book.len(), 4);
+        assert_eq!(book.entries(START).len(), 3);
+        assert_eq!(book.entries(0).len(), 0);
+
+        let entry = book.entries(START).iter().find(|e| e.weight == 10).unwrap();
+        assert_eq!(entry.to_move(), Some(Move::normal(Square::E2, Square::E4)));
+
+        // d2d4 and g1f3 tie; the one stored first wins
+        assert_eq!(book.probe(START), Some(Move::normal(Square::G1, Square::F3)));
+        assert_eq!(book.probe(OTHER), Some(Move::castle(Right::WhiteKing)));
+        assert_eq!(book.probe(1), None);
+    }
+
+    #[test]
+    fn decode() {
+        let decode = |raw_move| Entry { key: 0, raw_move, weight: 0, learn: 0 }.to_move();
+
+        assert_eq!(decode(0x0F3F), Some(Move::castle(Right::BlackKing)));
+        assert_eq!(decode(0x0F38), Some(Move::castle(Right::BlackQueen)));
+        assert_eq!(decode(0x0100), Some(Move::castle(Right::WhiteQueen)));
+
+        // a7a8q and b2c1n
+        assert_eq!(decode(0x4C38), Some(Move::promotion(File::A, Color::White, Promotion::Queen)));
+        assert_eq!(decode(0x1242), Move::try_promotion(Square::B2, Square::C1, Promotion::Knight));
+
+        // Invalid promotion piece
+        assert_eq!(decode(0x5C38), None);
+    }
+
+    #[test]
+    fn invalid_length() {
+        let bytes = fixture();
+        let err = OpeningBook::from_reader(&bytes[..(bytes.len() - 1)]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
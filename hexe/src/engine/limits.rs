@@ -1,11 +1,24 @@
+/// Bounds on a search, as given by the UCI `go` command.
+///
+/// A value of 0 means that the corresponding limit is not set.
+#[derive(Copy, Clone, Debug)]
 pub struct Limits {
+    /// Whether to search in pondering mode.
     pub ponder: bool,
+    /// Whether to search until told to stop.
     pub infinite: bool,
+    /// The number of moves until the next time control.
     pub moves_to_go: u32,
+    /// The time in milliseconds left on each player's clock.
     pub time: [u32; 2],
+    /// The increment in milliseconds for each player per move.
     pub inc: [u32; 2],
+    /// The maximum number of plies to search.
     pub depth: u32,
+    /// The maximum number of nodes to search.
     pub nodes: u32,
+    /// Search for a mate in this many moves.
     pub mate: u32,
+    /// The exact time in milliseconds to search for.
     pub move_time: u32,
 }
@@ -3,16 +3,30 @@
 // TODO lint when everything is implemented
 #![allow(unused_variables)]
 
+use std::io::{self, Write};
+use std::sync::atomic::Ordering;
 use std::usize;
 
+use core::mv::Move;
+use position::Position;
+use util;
+
 mod limits;
-pub(crate) use self::limits::Limits;
+pub use self::limits::Limits;
+
+mod search;
+use self::search::{Iteration, Search};
+pub use self::search::SearchInfo;
 
 mod thread;
 use self::thread::Pool;
 
+mod time;
+pub use self::time::TimeManager;
+
 mod uci;
 pub use self::uci::Uci;
+use self::uci::{UciMove, UciScore};
 
 /// The maximum number of threads that may be running in an
 /// [`Engine`](struct.Engine.html)'s thread pool.
@@ -81,7 +95,7 @@ impl Engine {
     pub fn options(&self) -> Options {
         Options {
             num_threads: self.num_threads(),
-            hash_size: self.pool.shared().table.size_mb(),
+            hash_size: self.pool.shared().table.lock().size_mb(),
         }
     }
 
@@ -91,6 +105,73 @@ impl Engine {
         Uci::from(self)
     }
 
+    /// Searches `pos` within `limits`, returning the best move found.
+    ///
+    /// The search is run on the current thread, deepening one ply at a time
+    /// and reporting each completed depth via a UCI `info` line, followed by
+    /// `bestmove`. Without a depth limit, the search deepens until it reaches
+    /// the node, mate, or time limits, or a default depth if none is given.
+    ///
+    /// The best move is always legal, or null if `pos` has no legal moves.
+    pub fn search(&mut self, pos: &Position, limits: Limits) -> Move {
+        self.search_info(pos, limits).best_move()
+    }
+
+    /// Searches `pos` within `limits` like [`search`](#method.search),
+    /// returning the result of the deepest completed iteration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing to `stdout` fails.
+    pub fn search_info(&mut self, pos: &Position, limits: Limits) -> SearchInfo {
+        let stdout = io::stdout();
+        match self.search_with(pos, limits, &[], &mut stdout.lock()) {
+            Ok(info) => info,
+            Err(err) => panic!("failed printing to stdout: {}", err),
+        }
+    }
+
+    /// Searches `pos` within `limits`, only considering `moves` at the root if
+    /// any are given, and writes the UCI `info` and `bestmove` lines to `out`.
+    ///
+    /// In debug mode, the time limits and table cutoffs are also written as
+    /// `info string` lines.
+    fn search_with<W: Write>(
+        &mut self,
+        pos: &Position,
+        limits: Limits,
+        moves: &[Move],
+        out: &mut W,
+    ) -> io::Result<SearchInfo> {
+        let debug = self.debug();
+        if debug {
+            let time = TimeManager::new(&limits, pos.player(), pos.fullmoves());
+            writeln!(out, "info string soft limit {:?} hard limit {:?}",
+                     time.soft_limit(), time.hard_limit())?;
+            if !moves.is_empty() {
+                writeln!(out, "info string searching {} moves", moves.len())?;
+            }
+        }
+
+        let mut table  = self.pool.shared().table.lock();
+        let mut search = Search::new(&mut table, &limits, pos);
+        search.search_moves(moves);
+
+        let mut result = Ok(());
+        let info = search.run(pos, |iteration| {
+            if result.is_ok() {
+                result = write_info(out, iteration);
+            }
+        });
+        result?;
+
+        if debug {
+            writeln!(out, "info string table cutoffs {}", search.table_cutoffs)?;
+        }
+        writeln!(out, "bestmove {}", UciMove(info.best_move()))?;
+        Ok(info)
+    }
+
     /// Ceases execution of all current jobs.
     pub fn stop_all(&self) {
         self.pool.stop_all();
@@ -130,7 +211,20 @@ impl Engine {
 
     /// Returns the engine's current hash table size.
     pub fn hash_size(&self) -> usize {
-        self.pool.shared().table.size_mb()
+        self.pool.shared().table.lock().size_mb()
+    }
+
+    /// Returns whether the engine reports extra diagnostic info.
+    #[inline]
+    pub fn debug(&self) -> bool {
+        self.pool.shared().debug.load(Ordering::Relaxed)
+    }
+
+    /// Sets whether the engine reports extra diagnostic info, such as via
+    /// `info string` messages during a search.
+    #[inline]
+    pub fn set_debug(&self, debug: bool) {
+        self.pool.shared().debug.store(debug, Ordering::Relaxed);
     }
 
     /// Sets the engine's hash table size to `size` [MiB], returning `false` if
@@ -210,3 +304,108 @@ pub struct Options {
     /// engine's transposition table.
     pub hash_size: usize,
 }
+
+/// Writes `iteration` to `out` as a UCI `info` line.
+fn write_info<W: Write>(out: &mut W, iteration: &Iteration) -> io::Result<()> {
+    write!(out, "info depth {} score {} nodes {} time {} pv",
+           iteration.depth, UciScore(iteration.score), iteration.nodes,
+           util::millis(iteration.time))?;
+    for &mv in &iteration.pv {
+        write!(out, " {}", UciMove(mv))?;
+    }
+    writeln!(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mv::Move;
+    use core::square::Square;
+
+    #[test]
+    fn search_mate_in_one() {
+        let mut engine = Engine::builder().num_threads(1).build();
+
+        let cases = [
+            ("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", Square::A1, Square::A8),
+            ("r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5Q2/PPPP1PPP/RNB1K1NR w KQkq - 0 1",
+             Square::F3, Square::F7),
+            ("6rk/6pp/8/6N1/8/8/8/6K1 w - - 0 1", Square::G5, Square::F7),
+        ];
+
+        for &(fen, src, dst) in &cases {
+            let pos = Position::from_fen(fen).unwrap();
+            let mut limits = Limits::default();
+            limits.depth = 2;
+
+            let mv = engine.search(&pos, limits);
+            assert_eq!(mv, Move::normal(src, dst), "{}", fen);
+            assert!(pos.play(mv).is_checkmate(), "{}", fen);
+        }
+    }
+
+    #[test]
+    fn search_limits() {
+        let mut engine = Engine::builder().num_threads(1).build();
+        let pos = Position::default();
+
+        let mut limits = Limits::default();
+        limits.nodes = 100;
+        let info = engine.search_info(&pos, limits);
+        assert!(pos.legal_moves().contains(&info.best_move()));
+        assert_eq!(info.nodes, 100);
+
+        // Checkmated player has no move to make
+        let mated = Position::from_fen("R5k1/5ppp/8/8/8/8/8/6K1 b - - 0 1").unwrap();
+        let info = engine.search_info(&mated, Limits::default());
+        assert!(info.best_move().is_null());
+        assert!(info.pv.is_empty());
+    }
+
+    #[test]
+    fn search_depth_one() {
+        let mut engine = Engine::builder().num_threads(1).build();
+        let pos = Position::default();
+
+        let mut limits = Limits::default();
+        limits.depth = 1;
+        let info = engine.search_info(&pos, limits);
+
+        // Only the positions after each root move are visited, some of them
+        // twice when a null window search is repeated with the full window
+        let moves = pos.legal_moves().len() as u64;
+        assert!(info.nodes >= moves && info.nodes < 2 * moves, "{}", info.nodes);
+        assert_eq!(info.depth, 1);
+        assert_eq!(info.pv.len(), 1);
+    }
+
+    #[test]
+    fn search_nodes_cap() {
+        let mut engine = Engine::builder().num_threads(1).build();
+        let pos = Position::default();
+
+        let mut limits = Limits::default();
+        limits.depth = 8;
+        limits.nodes = 1_000;
+        let info = engine.search_info(&pos, limits);
+
+        assert_eq!(info.nodes, 1_000);
+        assert!(info.depth < 8);
+        assert!(pos.legal_moves().contains(&info.best_move()));
+    }
+
+    #[test]
+    fn search_mate_limit() {
+        let mut engine = Engine::builder().num_threads(1).build();
+        let pos = Position::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").unwrap();
+
+        // Mate in one is seen at depth 2, short of the 4 plies allowed
+        let mut limits = Limits::default();
+        limits.mate = 2;
+        let info = engine.search_info(&pos, limits);
+
+        assert_eq!(info.depth, 2);
+        assert_eq!(info.score, search::MATE - 1);
+        assert_eq!(info.best_move(), Move::normal(Square::A1, Square::A8));
+    }
+}
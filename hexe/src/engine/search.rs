@@ -0,0 +1,636 @@
+//! Principal variation search.
+
+use std::cmp;
+use std::time::{Duration, Instant};
+
+use core::mv::MoveVec;
+use engine::{Limits, TimeManager};
+use prelude::*;
+use table::{Bound, Table};
+
+/// The score of delivering checkmate at the root.
+pub const MATE: i32 = 32_000;
+
+/// A score greater than any that can be returned by a search.
+pub const INFINITY: i32 = MATE + 1;
+
+/// The maximum number of plies from the root, beyond which any score within
+/// `MAX_PLY` of `MATE` is considered a mate score.
+pub const MAX_PLY: u32 = 128;
+
+/// The depth searched when no depth limit is given.
+pub const DEFAULT_DEPTH: u32 = 4;
+
+/// The number of nodes searched between checks of the clock.
+const CLOCK_INTERVAL: u64 = 1024;
+
+/// The score in centipawns for each square attacked by a knight, bishop, rook,
+/// or queen.
+const MOBILITY_WEIGHT: i32 = 2;
+
+/// Returns the material and mobility balance of `pos` in centipawns, from the
+/// perspective of the player to move.
+pub fn evaluate(pos: &Position) -> i32 {
+    let board  = pos.board();
+    let player = pos.player();
+
+    let material = board.material(player) - board.material(!player);
+    material + MOBILITY_WEIGHT * (mobility(pos, player) - mobility(pos, !player))
+}
+
+/// Returns the number of squares attacked by the non-pawn, non-king pieces of
+/// `color` that are not occupied by its own pieces.
+fn mobility(pos: &Position, color: Color) -> i32 {
+    let board    = pos.board();
+    let occupied = board.occupied();
+    let own      = board.occupied_by(color);
+
+    let mut count = 0;
+    for &role in &[Role::Knight, Role::Bishop, Role::Rook, Role::Queen] {
+        let piece = Piece::new(role, color);
+        for sq in board.bits(piece) {
+            count += sq.attacks(piece, occupied).mobility(own);
+        }
+    }
+    count as i32
+}
+
+/// A depth-limited [principal variation search][pvs] over the game tree.
+///
+/// Best moves are stored in the transposition table and tried first when a
+/// position is revisited. Stored scores cut the search short when they were
+/// searched at least as deep and their bound settles the window.
+///
+/// [pvs]: https://www.chessprogramming.org/Principal_Variation_Search
+pub struct Search<'a> {
+    /// The number of nodes visited so far.
+    pub nodes: u64,
+    /// The number of nodes cut off by a score stored in the table.
+    pub table_cutoffs: u64,
+    table: &'a mut Table,
+    search_moves: Vec<Move>,
+    time: TimeManager,
+    start: Instant,
+    elapsed: Duration,
+    depth: u32,
+    max_depth: u32,
+    mate_plies: u32,
+    aborted: bool,
+}
+
+impl<'a> Search<'a> {
+    /// Creates a search of `pos` that stores results in `table` and stops
+    /// upon reaching the depth, node, mate, or time limits.
+    ///
+    /// Without a depth limit, [`run`](#method.run) deepens until it reaches
+    /// another limit, or a default depth if none is given. A mate limit of `n`
+    /// moves bounds the depth to the `2 * n` plies needed to see the mate.
+    pub fn new(table: &'a mut Table, limits: &Limits, pos: &Position) -> Search<'a> {
+        let time    = TimeManager::new(limits, pos.player(), pos.fullmoves());
+        let bounded = limits.nodes != 0 || time.hard_limit().is_some();
+        let max_depth = match (limits.depth, limits.mate) {
+            (0, 0) if bounded => MAX_PLY,
+            (0, 0) => DEFAULT_DEPTH,
+            (0, mate) => cmp::min(mate.saturating_mul(2), MAX_PLY),
+            (depth, _) => depth,
+        };
+        Search {
+            nodes: 0,
+            table_cutoffs: 0,
+            table,
+            search_moves: Vec::new(),
+            time,
+            start: Instant::now(),
+            elapsed: Duration::from_secs(0),
+            depth: 0,
+            max_depth,
+            mate_plies: limits.mate.saturating_mul(2).saturating_sub(1),
+            aborted: false,
+        }
+    }
+
+    /// Restricts the moves searched at the root to those in `moves`.
+    ///
+    /// If none of `moves` are legal, all legal moves are searched.
+    pub fn search_moves(&mut self, moves: &[Move]) {
+        self.search_moves = moves.to_vec();
+    }
+
+    /// Returns the time elapsed since the search was created.
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Checks the limits, returning whether the search should stop.
+    ///
+    /// The clock is only read every `CLOCK_INTERVAL` nodes.
+    fn should_abort(&mut self) -> bool {
+        if !self.aborted {
+            if self.nodes % CLOCK_INTERVAL == 0 {
+                self.elapsed = self.elapsed();
+            }
+            self.aborted = self.time.should_stop(self.elapsed, self.nodes, self.depth);
+        }
+        self.aborted
+    }
+
+    /// Moves the best move stored for `pos`, if any, to the front of `moves`.
+    fn order(&self, pos: &Position, moves: &mut MoveVec) {
+        if let Some(entry) = self.table.probe(pos.key()) {
+            let best = entry.mv();
+            if let Some(i) = moves.iter().position(|&mv| mv == best) {
+                moves[..=i].rotate_right(1);
+            }
+        }
+    }
+
+    /// Stores `mv` as the best move for `pos` at `depth` and `ply`, where
+    /// `score` is bounded by `bound`.
+    fn store(
+        &mut self,
+        pos: &Position,
+        mv: Move,
+        score: i32,
+        bound: Bound,
+        depth: u32,
+        ply: u32,
+    ) {
+        let depth = cmp::min(depth, u8::max_value() as u32) as u8;
+        self.table.store(pos.key(), mv, to_table(score, ply), depth, bound);
+    }
+
+    /// Returns the score stored for `pos` if it was searched to at least
+    /// `depth` and settles the fail-hard window `[alpha, beta]`.
+    fn probe_cutoff(
+        &self,
+        pos: &Position,
+        depth: u32,
+        ply: u32,
+        alpha: i32,
+        beta: i32,
+    ) -> Option<i32> {
+        let entry = self.table.probe(pos.key())?;
+        if (entry.depth() as u32) < depth {
+            return None;
+        }
+        let score = from_table(entry.val(), ply);
+        match entry.bound() {
+            Bound::Exact => Some(cmp::max(alpha, cmp::min(score, beta))),
+            Bound::Lower if score >= beta => Some(beta),
+            Bound::Upper if score <= alpha => Some(alpha),
+            _ => None,
+        }
+    }
+
+    /// Returns the principal variation of up to `len` moves starting with
+    /// `best`, following the best moves stored in the table.
+    pub fn pv(&self, pos: &Position, best: Move, len: u32) -> Vec<Move> {
+        let mut pv  = vec![best];
+        let mut pos = pos.play(best);
+        while pv.len() < len as usize {
+            let mv = match self.table.probe(pos.key()) {
+                Some(entry) => entry.mv(),
+                None => break,
+            };
+            if !pos.legal_moves().contains(&mv) {
+                break;
+            }
+            pv.push(mv);
+            pos = pos.play(mv);
+        }
+        pv
+    }
+
+    /// Searches `pos` up to the depth given by the limits, calling `report`
+    /// after each completed iteration.
+    ///
+    /// The result is that of the deepest completed iteration. If the first
+    /// iteration is aborted, its depth is 0 and its move is the best among
+    /// those fully searched, or else the first move tried.
+    pub fn run<F>(&mut self, pos: &Position, mut report: F) -> SearchInfo
+        where F: FnMut(&Iteration)
+    {
+        let mut last = None;
+        let max_depth = self.max_depth;
+        let (best, score) = self.iterate(pos, max_depth, |iteration| {
+            report(iteration);
+            last = Some(iteration.clone());
+        });
+
+        match last {
+            Some(Iteration { depth, score, pv, .. }) => {
+                SearchInfo { nodes: self.nodes, depth, score, pv }
+            },
+            None => SearchInfo {
+                nodes: self.nodes,
+                depth: 0,
+                score,
+                pv: best.into_iter().collect(),
+            },
+        }
+    }
+
+    /// Searches `pos` at increasing depths up to `max_depth` or until the
+    /// search is aborted, calling `report` after each completed iteration.
+    /// With a mate limit, the search also stops once a mate is found within
+    /// the limit.
+    ///
+    /// Each iteration tries the previous iteration's best move first. The
+    /// result is that of the deepest completed iteration, or else that of the
+    /// aborted first iteration. The move is `None` if there are no legal
+    /// moves or `max_depth` is 0.
+    pub fn iterate<F>(&mut self, pos: &Position, max_depth: u32, mut report: F)
+        -> (Option<Move>, i32)
+        where F: FnMut(&Iteration)
+    {
+        if max_depth == 0 {
+            return self.root(pos, 0);
+        }
+
+        let mut result = (None, 0);
+        for depth in 1..=max_depth {
+            let next = self.root(pos, depth);
+            if self.aborted {
+                // Fall back to the first move tried if nothing completed
+                if depth == 1 {
+                    result = next;
+                }
+                break;
+            }
+            result = next;
+
+            let best = match result.0 {
+                Some(best) => best,
+                None => break,
+            };
+            report(&Iteration {
+                depth,
+                score: result.1,
+                nodes: self.nodes,
+                time:  self.elapsed(),
+                pv:    self.pv(pos, best, depth),
+            });
+
+            if self.mate_plies != 0 && result.1 >= MATE - self.mate_plies as i32 {
+                break;
+            }
+            if !self.time.should_deepen(self.elapsed()) {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Searches `pos` to `depth`, returning the best move and its score from
+    /// the perspective of the player to move.
+    ///
+    /// The move is `None` if there are no legal moves or `depth` is 0. If the
+    /// search is aborted, the best move among those fully searched is
+    /// returned, or else the first move tried.
+    pub fn root(&mut self, pos: &Position, depth: u32) -> (Option<Move>, i32) {
+        self.depth = depth;
+        if depth == 0 {
+            return (None, self.pvs(pos, 0, 0, -INFINITY, INFINITY));
+        }
+
+        let mut moves = MoveVec::new();
+        pos.gen(&mut moves).legal();
+        if moves.is_empty() {
+            return (None, terminal(pos, 0));
+        }
+        if !self.search_moves.is_empty() {
+            let mut allowed = MoveVec::new();
+            for &mv in moves.iter().filter(|mv| self.search_moves.contains(mv)) {
+                allowed.push(mv);
+            }
+            if !allowed.is_empty() {
+                moves = allowed;
+            }
+        }
+        self.order(pos, &mut moves);
+
+        let mut best  = moves[0];
+        let mut alpha = -INFINITY;
+        for (i, &mv) in moves.iter().enumerate() {
+            let child = pos.play(mv);
+            let score = if i == 0 {
+                -self.pvs(&child, depth - 1, 1, -INFINITY, -alpha)
+            } else {
+                self.null_window_search(&child, depth - 1, 1, alpha, INFINITY)
+            };
+            if self.aborted {
+                break;
+            }
+            if score > alpha {
+                alpha = score;
+                best  = mv;
+            }
+        }
+
+        if !self.aborted {
+            self.store(pos, best, alpha, Bound::Exact, depth, 0);
+        }
+        (Some(best), alpha)
+    }
+
+    /// Searches `child` with the zero-width window `[alpha, alpha + 1]`,
+    /// re-searching with the full `[alpha, beta]` window if it fails high.
+    ///
+    /// The score is from the perspective of the player moving into `child`.
+    pub fn null_window_search(
+        &mut self,
+        child: &Position,
+        depth: u32,
+        ply: u32,
+        alpha: i32,
+        beta: i32,
+    ) -> i32 {
+        let score = -self.pvs(child, depth, ply, -alpha - 1, -alpha);
+        if score > alpha && score < beta {
+            -self.pvs(child, depth, ply, -beta, -alpha)
+        } else {
+            score
+        }
+    }
+
+    /// Performs a fail-hard search of `pos` within `[alpha, beta]`, searching
+    /// the first move with the full window and the rest with a null window.
+    ///
+    /// Returns 0 if the search is aborted.
+    fn pvs(
+        &mut self,
+        pos: &Position,
+        depth: u32,
+        ply: u32,
+        mut alpha: i32,
+        beta: i32,
+    ) -> i32 {
+        if self.should_abort() {
+            return 0;
+        }
+        self.nodes += 1;
+        if depth == 0 {
+            return evaluate(pos);
+        }
+        if let Some(score) = self.probe_cutoff(pos, depth, ply, alpha, beta) {
+            self.table_cutoffs += 1;
+            return score;
+        }
+
+        let mut moves = MoveVec::new();
+        pos.gen(&mut moves).legal();
+        if moves.is_empty() {
+            return terminal(pos, ply);
+        }
+        self.order(pos, &mut moves);
+
+        let mut best  = moves[0];
+        let mut bound = Bound::Upper;
+        for (i, &mv) in moves.iter().enumerate() {
+            let child = pos.play(mv);
+            let score = if i == 0 {
+                -self.pvs(&child, depth - 1, ply + 1, -beta, -alpha)
+            } else {
+                self.null_window_search(&child, depth - 1, ply + 1, alpha, beta)
+            };
+            if self.aborted {
+                return 0;
+            }
+            if score >= beta {
+                self.store(pos, mv, beta, Bound::Lower, depth, ply);
+                return beta;
+            }
+            if score > alpha {
+                alpha = score;
+                best  = mv;
+                bound = Bound::Exact;
+            }
+        }
+        self.store(pos, best, alpha, bound, depth, ply);
+        alpha
+    }
+}
+
+/// The result of a completed iteration of [`Search::iterate`].
+///
+/// [`Search::iterate`]: struct.Search.html#method.iterate
+#[derive(Clone, Debug)]
+pub struct Iteration {
+    /// The depth searched.
+    pub depth: u32,
+    /// The score from the perspective of the player to move.
+    pub score: i32,
+    /// The total number of nodes searched so far.
+    pub nodes: u64,
+    /// The total time spent searching so far.
+    pub time: Duration,
+    /// The principal variation, starting with the best move.
+    pub pv: Vec<Move>,
+}
+
+/// The outcome of a [`Search::run`](struct.Search.html#method.run).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchInfo {
+    /// The total number of nodes searched.
+    pub nodes: u64,
+    /// The depth of the deepest completed iteration.
+    pub depth: u32,
+    /// The score from the perspective of the player to move.
+    pub score: i32,
+    /// The principal variation, starting with the best move.
+    ///
+    /// This is empty if there are no legal moves.
+    pub pv: Vec<Move>,
+}
+
+impl SearchInfo {
+    /// Returns the best move found, or null if there are no legal moves.
+    #[inline]
+    pub fn best_move(&self) -> Move {
+        self.pv.first().cloned().unwrap_or(Move::null())
+    }
+}
+
+/// Returns `score` at `ply` as stored in the table, with mate scores counted
+/// from the stored position rather than the root.
+fn to_table(score: i32, ply: u32) -> i16 {
+    let score = if score >= MATE - MAX_PLY as i32 {
+        score + ply as i32
+    } else if score <= MAX_PLY as i32 - MATE {
+        score - ply as i32
+    } else {
+        score
+    };
+    score as i16
+}
+
+/// Returns the score stored in the table as `val` for use at `ply`, undoing
+/// [`to_table`](fn.to_table.html).
+fn from_table(val: i16, ply: u32) -> i32 {
+    let score = i32::from(val);
+    if score >= MATE - MAX_PLY as i32 {
+        score - ply as i32
+    } else if score <= MAX_PLY as i32 - MATE {
+        score + ply as i32
+    } else {
+        score
+    }
+}
+
+/// Returns the score of `pos` when it has no legal moves.
+fn terminal(pos: &Position, ply: u32) -> i32 {
+    if pos.is_check() {
+        ply as i32 - MATE
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Plain fail-hard alpha-beta, used as a reference for the search.
+    fn alpha_beta(pos: &Position, depth: u32, ply: u32, mut alpha: i32, beta: i32) -> i32 {
+        if depth == 0 {
+            return evaluate(pos);
+        }
+
+        let mut moves = MoveVec::new();
+        pos.gen(&mut moves).legal();
+        if moves.is_empty() {
+            return terminal(pos, ply);
+        }
+
+        for &mv in moves.iter() {
+            let score = -alpha_beta(&pos.play(mv), depth - 1, ply + 1, -beta, -alpha);
+            if score >= beta {
+                return beta;
+            }
+            if score > alpha {
+                alpha = score;
+            }
+        }
+        alpha
+    }
+
+    fn alpha_beta_root(pos: &Position, depth: u32) -> (Option<Move>, i32) {
+        let mut moves = MoveVec::new();
+        pos.gen(&mut moves).legal();
+
+        let mut best  = None;
+        let mut alpha = -INFINITY;
+        for &mv in moves.iter() {
+            let score = -alpha_beta(&pos.play(mv), depth - 1, 1, -INFINITY, -alpha);
+            if score > alpha {
+                alpha = score;
+                best  = Some(mv);
+            }
+        }
+        (best, alpha)
+    }
+
+    #[test]
+    fn pvs_matches_alpha_beta() {
+        let positions = [
+            Position::default(),
+            Position::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").unwrap(),
+            Position::from_fen("r3k3/8/8/3q4/8/2N5/8/4K3 w - - 0 1").unwrap(),
+            Position::from_fen("4k3/8/8/8/8/8/4q3/R3K3 w - - 0 1").unwrap(),
+            Position::from_fen("4k3/4p3/8/3B4/8/8/5PPP/6K1 b - - 0 1").unwrap(),
+        ];
+
+        for pos in &positions {
+            for depth in 1..4 {
+                // A fresh table keeps the root move order the same as the
+                // reference, which picks the first of equally scored moves
+                let mut table  = Table::new(1);
+                let mut search = Search::new(&mut table, &Limits::default(), pos);
+                assert_eq!(search.root(pos, depth), alpha_beta_root(pos, depth));
+            }
+        }
+    }
+
+    #[test]
+    fn iterate_mate() {
+        // Positions with mate in the given number of moves
+        let cases = [
+            ("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1",   1),
+            ("6k1/5ppp/8/8/8/8/1r6/R5K1 w - - 0 1", 2),
+            ("7k/8/8/8/8/8/R7/1R5K w - - 0 1",      2),
+        ];
+
+        for &(fen, moves) in &cases {
+            // Checkmate is only seen once the mated player's replies are
+            // generated, one ply past the mating move
+            let mate_plies = 2 * moves - 1;
+            let mate_depth = mate_plies + 1;
+            let mate_score = MATE - mate_plies as i32;
+
+            let pos = Position::from_fen(fen).unwrap();
+            let mut table = Table::new(1);
+            let mut iterations = Vec::new();
+
+            let mut search = Search::new(&mut table, &Limits::default(), &pos);
+            let (best, score) = search.iterate(&pos, 5, |i| iterations.push(i.clone()));
+
+            assert_eq!(iterations.len(), 5);
+            assert_eq!(score, mate_score);
+            assert_eq!(best, Some(iterations[4].pv[0]));
+
+            for iteration in &iterations {
+                if iteration.depth >= mate_depth {
+                    assert_eq!(iteration.score, mate_score, "{} at {}", fen, iteration.depth);
+                }
+                assert!(!iteration.pv.is_empty());
+                assert!(iteration.pv.len() <= iteration.depth as usize);
+                assert!(pos.legal_moves().contains(&iteration.pv[0]));
+            }
+
+            // The mating line ends in checkmate
+            let line = &iterations[4].pv;
+            if line.len() == mate_plies as usize {
+                let end = line.iter().fold(pos.clone(), |pos, &mv| pos.play(mv));
+                assert!(end.is_checkmate(), "{}", fen);
+            }
+        }
+    }
+
+    #[test]
+    fn table_cutoffs() {
+        let pos = Position::from_fen("r3k3/8/8/3q4/8/2N5/8/4K3 w - - 0 1").unwrap();
+        let mut table = Table::new(1);
+
+        let (first, nodes) = {
+            let mut search = Search::new(&mut table, &Limits::default(), &pos);
+            (search.root(&pos, 4), search.nodes)
+        };
+
+        // Searching again reuses the stored scores of the root's children
+        let mut search = Search::new(&mut table, &Limits::default(), &pos);
+        assert_eq!(search.root(&pos, 4), first);
+        assert!(search.nodes < nodes);
+    }
+
+    #[test]
+    fn table_mate_scores() {
+        for &ply in &[0, 3, 10] {
+            for &score in &[MATE - 5, 7 - MATE, 150, -40, 0] {
+                assert_eq!(from_table(to_table(score, ply), ply), score);
+            }
+        }
+        // Mate scores are stored relative to the position
+        assert_eq!(to_table(MATE - 5, 3), (MATE - 2) as i16);
+        assert_eq!(from_table(to_table(MATE - 5, 3), 1), MATE - 3);
+    }
+
+    #[test]
+    fn mate_in_one() {
+        let pos = Position::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").unwrap();
+        let mut table = Table::new(1);
+        let (mv, score) = Search::new(&mut table, &Limits::default(), &pos).root(&pos, 3);
+
+        assert_eq!(mv, Some(Move::normal(Square::A1, Square::A8)));
+        assert_eq!(score, MATE - 1);
+    }
+}
@@ -50,8 +50,14 @@ pub struct Shared {
     stop_cond: Condvar,
     stop_mutex: Mutex<()>,
 
+    /// Whether extra diagnostic info should be reported.
+    pub debug: AtomicBool,
+
     /// The transposition table.
-    pub table: Table,
+    ///
+    /// Locked for the duration of a search, since workers hold references to
+    /// `Shared` for as long as they run.
+    pub table: Mutex<Table>,
 }
 
 impl Shared {
@@ -66,6 +72,9 @@ impl Shared {
 #[cfg(test)]
 assert_impl!(shared; Shared, Send, Sync);
 
+// TODO: dispatch `go` here once searches can run on worker threads; it is
+// currently searched on the calling thread by `Engine::search`
+#[allow(dead_code)]
 pub enum Job {
     Search {
         limits: Limits,
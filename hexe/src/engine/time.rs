@@ -0,0 +1,192 @@
+use std::cmp;
+use std::time::Duration;
+
+use core::color::Color;
+use engine::Limits;
+
+/// The time in milliseconds kept in reserve for communication overhead.
+const MOVE_OVERHEAD: u32 = 50;
+
+/// The number of moves that a game is expected to last when `movestogo` is
+/// not given.
+const EXPECTED_MOVES: u32 = 50;
+
+/// The fewest number of moves assumed to remain in a game when `movestogo` is
+/// not given.
+const MIN_MOVES_LEFT: u32 = 20;
+
+/// The multiple of the soft limit that a search may run for before it is cut
+/// off mid-iteration.
+const HARD_FACTOR: u32 = 4;
+
+/// Decides when a search should stop, based on its limits.
+///
+/// A search should not start a new iteration after reaching the soft time
+/// limit, but it may finish the current one. Reaching the hard time limit or
+/// any other limit aborts the search immediately.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TimeManager {
+    soft: Option<Duration>,
+    hard: Option<Duration>,
+    max_nodes: u64,
+    max_depth: u32,
+}
+
+impl TimeManager {
+    /// Creates a time manager for `player` within `limits`, where
+    /// `move_number` is the current fullmove number.
+    ///
+    /// A fixed `movetime` is used as both limits. Otherwise, the remaining
+    /// clock time is split over `movestogo`, or over the moves expected to
+    /// remain at `move_number`, and the increment is added. Neither limit
+    /// exceeds the time left on the clock.
+    pub fn new(limits: &Limits, player: Color, move_number: u32) -> TimeManager {
+        let (soft, hard) = if limits.infinite || limits.ponder {
+            (None, None)
+        } else if limits.move_time != 0 {
+            let time = Duration::from_millis(limits.move_time.into());
+            (Some(time), Some(time))
+        } else {
+            match limits.time[player as usize] {
+                0 => (None, None),
+                time => {
+                    let inc = limits.inc[player as usize];
+                    let moves = match limits.moves_to_go {
+                        0 => {
+                            let left = EXPECTED_MOVES.saturating_sub(move_number);
+                            cmp::max(left, MIN_MOVES_LEFT)
+                        },
+                        n => n,
+                    };
+
+                    let max  = time.saturating_sub(MOVE_OVERHEAD);
+                    let soft = cmp::min((time / moves).saturating_add(inc), max);
+                    let hard = cmp::min(soft.saturating_mul(HARD_FACTOR), max);
+                    let millis = |ms: u32| Some(Duration::from_millis(ms.into()));
+                    (millis(soft), millis(hard))
+                },
+            }
+        };
+
+        TimeManager {
+            soft,
+            hard,
+            max_nodes: limits.nodes.into(),
+            max_depth: limits.depth,
+        }
+    }
+
+    /// Returns the time after which no new iteration should be started, or
+    /// `None` if the search is not bound by time.
+    #[inline]
+    pub fn soft_limit(&self) -> Option<Duration> {
+        self.soft
+    }
+
+    /// Returns the time after which the search should be aborted, or `None`
+    /// if the search is not bound by time.
+    #[inline]
+    pub fn hard_limit(&self) -> Option<Duration> {
+        self.hard
+    }
+
+    /// Returns whether a search that has run for `elapsed`, visited `nodes`
+    /// nodes, and is searching to `depth` should be aborted immediately.
+    pub fn should_stop(&self, elapsed: Duration, nodes: u64, depth: u32) -> bool {
+        (self.max_nodes != 0 && nodes >= self.max_nodes)
+            || (self.max_depth != 0 && depth > self.max_depth)
+            || self.hard.map_or(false, |hard| elapsed >= hard)
+    }
+
+    /// Returns whether a search that has run for `elapsed` may start another
+    /// iteration.
+    #[inline]
+    pub fn should_deepen(&self, elapsed: Duration) -> bool {
+        self.soft.map_or(true, |soft| elapsed < soft)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(ms: u32) -> Option<Duration> {
+        Some(Duration::from_millis(ms.into()))
+    }
+
+    #[test]
+    fn sudden_death() {
+        let mut limits = Limits::default();
+        limits.time = [60_000, 10_000];
+
+        let white = TimeManager::new(&limits, Color::White, 1);
+        let soft  = 60_000 / (EXPECTED_MOVES - 1);
+        assert_eq!(white.soft_limit(), millis(soft));
+        assert_eq!(white.hard_limit(), millis(soft * HARD_FACTOR));
+
+        // Later in the game, fewer moves are assumed to remain
+        let black = TimeManager::new(&limits, Color::Black, 40);
+        assert_eq!(black.soft_limit(), millis(10_000 / MIN_MOVES_LEFT));
+
+        // Neither limit exceeds the clock, even with a large increment
+        limits.time[1] = 100;
+        limits.inc[1]  = 1_000;
+        let black = TimeManager::new(&limits, Color::Black, 40);
+        assert_eq!(black.soft_limit(), millis(100 - MOVE_OVERHEAD));
+        assert_eq!(black.hard_limit(), millis(100 - MOVE_OVERHEAD));
+
+        let soft = white.soft_limit().unwrap();
+        let hard = white.hard_limit().unwrap();
+        assert!(white.should_deepen(soft / 2));
+        assert!(!white.should_deepen(soft));
+        assert!(!white.should_stop(soft, 0, 1));
+        assert!(white.should_stop(hard, 0, 1));
+    }
+
+    #[test]
+    fn moves_to_go() {
+        let mut limits = Limits::default();
+        limits.time = [30_000, 30_000];
+        limits.inc  = [1_000, 0];
+        limits.moves_to_go = 10;
+
+        let white = TimeManager::new(&limits, Color::White, 30);
+        assert_eq!(white.soft_limit(), millis(3_000 + 1_000));
+        assert_eq!(white.hard_limit(), millis(4_000 * HARD_FACTOR));
+
+        let black = TimeManager::new(&limits, Color::Black, 30);
+        assert_eq!(black.soft_limit(), millis(3_000));
+    }
+
+    #[test]
+    fn move_time() {
+        let mut limits = Limits::default();
+        limits.move_time = 1_500;
+        limits.time = [60_000, 60_000];
+
+        let time = TimeManager::new(&limits, Color::White, 10);
+        assert_eq!(time.soft_limit(), millis(1_500));
+        assert_eq!(time.hard_limit(), millis(1_500));
+        assert!(!time.should_stop(Duration::from_millis(1_499), 0, 1));
+        assert!(time.should_stop(Duration::from_millis(1_500), 0, 1));
+
+        limits.infinite = true;
+        let time = TimeManager::new(&limits, Color::White, 10);
+        assert_eq!(time.hard_limit(), None);
+        assert!(time.should_deepen(Duration::from_secs(3_600)));
+        assert!(!time.should_stop(Duration::from_secs(3_600), 0, 1));
+    }
+
+    #[test]
+    fn nodes_and_depth() {
+        let mut limits = Limits::default();
+        limits.nodes = 100;
+        limits.depth = 3;
+
+        let time = TimeManager::new(&limits, Color::White, 1);
+        let zero = Duration::from_millis(0);
+        assert!(!time.should_stop(zero, 99, 3));
+        assert!(time.should_stop(zero, 100, 3));
+        assert!(time.should_stop(zero, 0, 4));
+    }
+}
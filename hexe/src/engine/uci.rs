@@ -1,13 +1,16 @@
 use super::*;
 
-use std::io::{self, BufRead};
+use std::fmt;
+use std::io::{self, BufRead, Write};
 use std::mem;
 use std::str;
+use std::time::Instant;
 
 use core::color::Color;
 use core::mv::Move;
 use engine::Limits;
-use engine::thread::Job;
+use engine::search::{self, MATE, MAX_PLY};
+use position::Position;
 
 const WHITE: usize = Color::White as usize;
 const BLACK: usize = Color::Black as usize;
@@ -33,12 +36,42 @@ impl Default for Limits {
 
 type UciIter<'a> = str::SplitWhitespace<'a>;
 
+/// Formats a move in the long algebraic notation used by UCI, such as `e2e4`
+/// or `e7e8q`. The null move is written as `0000`.
+pub(crate) struct UciMove(pub Move);
+
+impl fmt::Display for UciMove {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.map_uci(|s| f.write_str(s))
+    }
+}
+
+/// Formats a search score as either `cp <centipawns>` or `mate <moves>`, where
+/// a negative number of moves means that the engine is getting mated.
+pub(crate) struct UciScore(pub i32);
+
+impl fmt::Display for UciScore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let score = self.0;
+        let plies = MATE - score.abs();
+        if plies <= MAX_PLY as i32 {
+            let moves = (plies + 1) / 2;
+            write!(f, "mate {}", if score > 0 { moves } else { -moves })
+        } else {
+            write!(f, "cp {}", score)
+        }
+    }
+}
+
 /// Runs the engine via the [Universal Chess Interface][uci] (UCI) protocol.
 ///
 /// [uci]: http://wbec-ridderkerk.nl/html/UCIProtocol.html
 pub struct Uci<'a> {
     engine: &'a mut Engine,
 
+    // The position set via `position`
+    position: Position,
+
     // Reusable string buffers
     string_buf_0: String,
     string_buf_1: String,
@@ -49,6 +82,7 @@ impl<'a> From<&'a mut Engine> for Uci<'a> {
     fn from(engine: &'a mut Engine) -> Uci<'a> {
         Uci {
             engine,
+            position: Position::default(),
             string_buf_0: String::new(),
             string_buf_1: String::new(),
         }
@@ -147,7 +181,13 @@ impl<'a> Uci<'a> {
             "ucinewgame" => self.cmd_new_game(),
             "go"         => self.cmd_go(split),
             "isready"    => println!("readyok"),
+            "register"   => self.cmd_register(split),
+            "debug"      => self.cmd_debug(split),
             "resume"     => self.engine.resume_all(),
+            // Non-standard commands for debugging
+            "d"          => println!("{}", self.position),
+            "eval"       => self.cmd_eval(),
+            "perft"      => self.cmd_perft(split),
             _            => unknown_command!(line),
         }
         true
@@ -162,6 +202,42 @@ impl<'a> Uci<'a> {
         );
     }
 
+    fn cmd_eval(&self) {
+        let score = search::evaluate(&self.position);
+        println!("{} (from {}'s perspective)", UciScore(score), self.position.player());
+    }
+
+    fn cmd_perft(&self, mut iter: UciIter) {
+        match iter.next() {
+            Some(depth) => match depth.parse() {
+                Ok(depth) => {
+                    let stdout = io::stdout();
+                    if let Err(err) = self.perft(depth, &mut stdout.lock()) {
+                        error!("Could not write perft results: {}", err);
+                    }
+                },
+                Err(err) => { parse_error!(depth, err); },
+            },
+            None => { error!("No perft depth provided"); },
+        }
+    }
+
+    /// Writes the perft count of the current position to `depth` following
+    /// each legal move, then the total and elapsed time, returning the total.
+    fn perft<W: Write>(&self, depth: u32, out: &mut W) -> io::Result<u64> {
+        let start  = Instant::now();
+        let divide = self.position.perft_divide(depth);
+        let time   = util::millis(start.elapsed());
+
+        let mut total = 0;
+        for &(mv, nodes) in &divide {
+            writeln!(out, "{}: {}", UciMove(mv), nodes)?;
+            total += nodes;
+        }
+        writeln!(out, "\nNodes searched: {}\nTime: {} ms", total, time)?;
+        Ok(total)
+    }
+
     fn cmd_uci(&self) {
         println!(id!(name));
         println!(id!(authors));
@@ -169,6 +245,21 @@ impl<'a> Uci<'a> {
         println!("uciok");
     }
 
+    fn cmd_debug(&mut self, mut iter: UciIter) {
+        match iter.next() {
+            Some("on")  => self.engine.set_debug(true),
+            Some("off") => self.engine.set_debug(false),
+            Some(other) => { error!("Invalid debug mode: {}", other); },
+            None        => { error!("No debug mode provided"); },
+        }
+    }
+
+    fn cmd_register(&mut self, _: UciIter) {
+        // Hexe is free software and requires no registration, so both
+        // `register later` and `register name <x> code <y>` are accepted
+        println!("registration ok");
+    }
+
     fn cmd_stop(&mut self) {
         self.engine.stop_all();
     }
@@ -177,8 +268,58 @@ impl<'a> Uci<'a> {
         unimplemented!();
     }
 
-    fn cmd_position(&mut self, _: UciIter) {
-        unimplemented!();
+    fn cmd_position(&mut self, mut iter: UciIter) {
+        let mut position = match iter.next() {
+            Some("startpos") => {
+                match iter.next() {
+                    Some("moves") | None => {},
+                    Some(other) => {
+                        error!("Expected 'moves', found: {}", other);
+                        return;
+                    },
+                }
+                Position::default()
+            },
+            Some("fen") => {
+                let fen = &mut self.string_buf_0;
+                fen.clear();
+                while let Some(next) = iter.next() {
+                    if next == "moves" {
+                        break;
+                    }
+                    if !fen.is_empty() {
+                        fen.push(' ');
+                    }
+                    fen.push_str(next);
+                }
+                match Position::from_fen(fen) {
+                    Ok(position) => position,
+                    Err(err) => {
+                        parse_error!(fen, err);
+                        return;
+                    },
+                }
+            },
+            Some(other) => {
+                error!("Invalid position: {}", other);
+                return;
+            },
+            None => {
+                error!("No position provided");
+                return;
+            },
+        };
+
+        for next in iter {
+            match read_move(&position, next) {
+                Some(mv) => position = position.play(mv),
+                None => {
+                    error!("Illegal move: {}", next);
+                    return;
+                },
+            }
+        }
+        self.position = position;
     }
 
     fn cmd_set_option(&mut self, mut iter: UciIter) {
@@ -249,7 +390,16 @@ impl<'a> Uci<'a> {
         unimplemented!();
     }
 
-    fn cmd_go(&mut self, mut iter: UciIter) {
+    fn cmd_go(&mut self, iter: UciIter) {
+        let stdout = io::stdout();
+        if let Err(err) = self.go(iter, &mut stdout.lock()) {
+            error!("Could not write search results: {}", err);
+        }
+    }
+
+    /// Searches the current position within the limits given by the `go`
+    /// arguments, writing each `info` line and the `bestmove` to `out`.
+    fn go<W: Write>(&mut self, mut iter: UciIter, out: &mut W) -> io::Result<()> {
         let mut limits = Limits::default();
         let mut moves  = Vec::<Move>::new();
 
@@ -283,15 +433,183 @@ impl<'a> Uci<'a> {
             }
         }
 
-        self.cmd_start_thinking(limits, moves.into());
+        self.engine.search_with(&self.position, limits, &moves, out).map(|_| ())
     }
 
     fn cmd_read_move(&self, s: &str) -> Option<Move> {
-        unimplemented!();
+        read_move(&self.position, s)
+    }
+}
+
+/// Returns the legal move of `pos` written as `s` in UCI notation.
+fn read_move(pos: &Position, s: &str) -> Option<Move> {
+    pos.legal_moves().iter().cloned().find(|mv| mv.map_uci(|uci| uci == s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uci_move() {
+        use core::castle::Right;
+        use core::piece::Promotion;
+        use core::square::{File, Square};
+
+        let cases = [
+            (Move::normal(Square::E2, Square::E4),                    "e2e4"),
+            (Move::castle(Right::BlackQueen),                         "e8c8"),
+            (Move::promotion(File::A, Color::White, Promotion::Knight), "a7a8n"),
+            (Move::null(),                                             "0000"),
+        ];
+        for &(mv, s) in &cases {
+            assert_eq!(UciMove(mv).to_string(), s);
+        }
+    }
+
+    #[test]
+    fn uci_score() {
+        assert_eq!(UciScore(35).to_string(),       "cp 35");
+        assert_eq!(UciScore(-120).to_string(),     "cp -120");
+        assert_eq!(UciScore(MATE - 1).to_string(), "mate 1");
+        assert_eq!(UciScore(MATE - 3).to_string(), "mate 2");
+        assert_eq!(UciScore(2 - MATE).to_string(), "mate -1");
+    }
+
+    #[test]
+    fn debug_commands() {
+        let mut engine = Engine::builder().num_threads(1).build();
+        let mut uci = engine.uci();
+
+        uci.run("d");
+        uci.run("eval");
+        uci.run("d\neval");
+        assert!(uci.run_line("d"));
+        assert!(uci.run_line("eval"));
     }
 
-    fn cmd_start_thinking(&mut self, limits: Limits, moves: Box<[Move]>) {
-        let job = Job::Search { limits, moves };
-        self.engine.pool.enqueue(job);
+    #[test]
+    fn register() {
+        let mut engine = Engine::builder().num_threads(1).build();
+        let mut uci = engine.uci();
+
+        assert!(uci.run_line("register later"));
+        assert!(uci.run_line("register name Hexe code 1234"));
+    }
+
+    #[test]
+    fn debug() {
+        let mut engine = Engine::builder().num_threads(1).build();
+        assert!(!engine.debug());
+
+        {
+            let mut uci = engine.uci();
+            assert!(uci.run_line("debug on"));
+            assert!(uci.engine().debug());
+            assert!(uci.run_line("isready"));
+        }
+        assert!(engine.debug());
+
+        engine.uci().run("debug off");
+        assert!(!engine.debug());
+    }
+
+    #[test]
+    fn position() {
+        let mut engine = Engine::builder().num_threads(1).build();
+        let mut uci = engine.uci();
+
+        uci.run("position startpos moves e2e4 e7e5 g1f3");
+        assert_eq!(uci.position.player(), Color::Black);
+        assert_eq!(uci.position.fullmoves(), 2);
+        let after = uci.position.key();
+
+        uci.run("position fen 4k3/8/8/8/8/8/8/R3K3 w Q - 0 1 moves e1c1");
+        assert_eq!(uci.position.king_square(Color::White), core::square::Square::C1);
+
+        // Invalid commands leave the position unchanged
+        let before = uci.position.key();
+        uci.run("position startpos moves e2e5");
+        uci.run("position fen 4k3/8 w - - 0 1");
+        uci.run("position");
+        uci.run("position startpos e2e4");
+        uci.run("position startpos moves moves e2e4");
+        uci.run("position fen 4k3/8/8/8/8/8/8/R3K3 w Q - 0 1 moves moves e1c1");
+        assert_eq!(uci.position.key(), before);
+
+        uci.run("position startpos moves e2e4 e7e5 g1f3");
+        assert_eq!(uci.position.key(), after);
+    }
+
+    #[test]
+    fn go() {
+        let mut engine = Engine::builder().num_threads(1).build();
+        let mut uci = engine.uci();
+        uci.run("position startpos");
+
+        let mut out = Vec::new();
+        uci.go("depth 2".split_whitespace(), &mut out).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        let mut lines = out.lines();
+        assert!(lines.next().unwrap().starts_with("info depth 1 "));
+        assert!(lines.next().unwrap().starts_with("info depth 2 "));
+        let best = lines.next().unwrap();
+        assert!(best.starts_with("bestmove "));
+        assert!(read_move(&uci.position, &best["bestmove ".len()..]).is_some());
+        assert_eq!(lines.next(), None);
+
+        let mut out = Vec::new();
+        uci.go("depth 1 searchmoves a2a3".split_whitespace(), &mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().ends_with("bestmove a2a3\n"));
+
+        assert!(uci.run_line("go depth 1"));
+    }
+
+    #[test]
+    fn go_debug() {
+        let mut engine = Engine::builder().num_threads(1).build();
+        let mut uci = engine.uci();
+        uci.run("position startpos");
+
+        let mut out = Vec::new();
+        uci.go("depth 2".split_whitespace(), &mut out).unwrap();
+        assert!(!String::from_utf8(out).unwrap().contains("info string"));
+
+        uci.run("debug on");
+        let mut out = Vec::new();
+        uci.go("depth 2".split_whitespace(), &mut out).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        let mut lines = out.lines();
+        assert_eq!(lines.next(), Some("info string soft limit None hard limit None"));
+        assert!(lines.next().unwrap().starts_with("info depth 1 "));
+        assert!(lines.next().unwrap().starts_with("info depth 2 "));
+        assert!(lines.next().unwrap().starts_with("info string table cutoffs "));
+        assert!(lines.next().unwrap().starts_with("bestmove "));
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn perft() {
+        let mut engine = Engine::builder().num_threads(1).build();
+        let mut uci = engine.uci();
+        uci.run("position startpos");
+
+        let mut out = Vec::new();
+        assert_eq!(uci.perft(2, &mut out).unwrap(), 400);
+
+        let out = String::from_utf8(out).unwrap();
+        let mut lines = out.lines();
+        for _ in 0..20 {
+            assert!(lines.next().unwrap().ends_with(": 20"));
+        }
+        assert_eq!(lines.next(), Some(""));
+        assert_eq!(lines.next(), Some("Nodes searched: 400"));
+        assert!(lines.next().unwrap().starts_with("Time: "));
+        assert_eq!(lines.next(), None);
+
+        assert!(uci.run_line("perft 1"));
+        assert!(uci.run_line("perft x"));
     }
 }
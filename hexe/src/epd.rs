@@ -0,0 +1,226 @@
+//! [Extended Position Description][epd] support.
+//!
+//! [epd]: https://www.chessprogramming.org/Extended_Position_Description
+
+use std::collections::BTreeMap;
+use std::error;
+use std::fmt;
+use std::str;
+
+use position::FromFenError;
+use prelude::*;
+
+/// An error returned when parsing an EPD line fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The position fields are malformed or describe an invalid position.
+    Position(FromFenError),
+    /// An operation is not terminated by a `;`.
+    Unterminated(String),
+    /// A `"` string operand is not closed.
+    UnterminatedString,
+    /// A `bm` or `am` operand is not a legal move in the position.
+    InvalidMove(String),
+}
+
+impl From<FromFenError> for Error {
+    #[inline]
+    fn from(err: FromFenError) -> Error {
+        Error::Position(err)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Position(ref err) => err.fmt(f),
+            Error::Unterminated(ref opcode) => {
+                write!(f, "operation {:?} is not terminated by ';'", opcode)
+            },
+            Error::UnterminatedString => f.write_str("unterminated string"),
+            Error::InvalidMove(ref san) => write!(f, "invalid move {:?}", san),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::Position(_)        => "invalid position",
+            Error::Unterminated(_)    => "unterminated operation",
+            Error::UnterminatedString => "unterminated string",
+            Error::InvalidMove(_)     => "invalid move",
+        }
+    }
+}
+
+/// A parsed [EPD][epd] line: a position followed by its operations.
+///
+/// [epd]: https://www.chessprogramming.org/Extended_Position_Description
+#[derive(Clone)]
+pub struct Epd {
+    /// The position described by the first four fields.
+    pub position: Position,
+    /// The operand of each operation, keyed by opcode.
+    ///
+    /// Surrounding quotes are removed from string operands.
+    pub operations: BTreeMap<String, String>,
+    /// The moves of the `bm` (best move) operation.
+    pub best_moves: Vec<Move>,
+    /// The moves of the `am` (avoid move) operation.
+    pub avoid_moves: Vec<Move>,
+}
+
+impl str::FromStr for Epd {
+    type Err = Error;
+
+    /// Parses an EPD line.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe::epd::Epd;
+    /// use hexe::prelude::*;
+    ///
+    /// let line = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - \
+    ///             bm e4 d4; id \"start\";";
+    /// let epd: Epd = line.parse().unwrap();
+    ///
+    /// assert_eq!(epd.id(), Some("start"));
+    /// assert_eq!(epd.best_moves, [
+    ///     Move::normal(Square::E2, Square::E4),
+    ///     Move::normal(Square::D2, Square::D4),
+    /// ]);
+    /// ```
+    fn from_str(s: &str) -> Result<Epd, Error> {
+        let mut rest = s;
+        let mut fen  = String::with_capacity(s.len());
+        for _ in 0..4 {
+            rest = rest.trim_left();
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            fen.push_str(&rest[..end]);
+            fen.push(' ');
+            rest = &rest[end..];
+        }
+        // EPD omits the move counters, which are not needed for the position
+        fen.push_str("0 1");
+
+        let mut epd = Epd {
+            position: Position::from_fen(&fen)?,
+            operations: BTreeMap::new(),
+            best_moves: Vec::new(),
+            avoid_moves: Vec::new(),
+        };
+
+        loop {
+            rest = rest.trim_left();
+            if rest.is_empty() {
+                break;
+            }
+
+            let end = rest.find(|ch: char| ch.is_whitespace() || ch == ';')
+                          .unwrap_or(rest.len());
+            let (opcode, tail) = rest.split_at(end);
+
+            let mut quoted = false;
+            let mut stop   = None;
+            for (i, ch) in tail.char_indices() {
+                match ch {
+                    '"' => quoted = !quoted,
+                    ';' if !quoted => {
+                        stop = Some(i);
+                        break;
+                    },
+                    _ => {},
+                }
+            }
+            let stop = match stop {
+                Some(stop) => stop,
+                None if quoted => return Err(Error::UnterminatedString),
+                None => return Err(Error::Unterminated(opcode.into())),
+            };
+            let operand = tail[..stop].trim();
+            rest = &tail[(stop + 1)..];
+
+            match opcode {
+                "bm" => epd.best_moves  = epd.parse_moves(operand)?,
+                "am" => epd.avoid_moves = epd.parse_moves(operand)?,
+                _ => {},
+            }
+
+            let operand = if operand.len() >= 2
+                && operand.starts_with('"')
+                && operand.ends_with('"')
+            {
+                &operand[1..(operand.len() - 1)]
+            } else {
+                operand
+            };
+            epd.operations.insert(opcode.into(), operand.into());
+        }
+
+        Ok(epd)
+    }
+}
+
+impl Epd {
+    /// Returns the operand of the `id` operation, if any.
+    #[inline]
+    pub fn id(&self) -> Option<&str> {
+        self.operation("id")
+    }
+
+    /// Returns the operand of the operation for `opcode`, if any.
+    #[inline]
+    pub fn operation(&self, opcode: &str) -> Option<&str> {
+        self.operations.get(opcode).map(|s| s.as_str())
+    }
+
+    fn parse_moves(&self, operand: &str) -> Result<Vec<Move>, Error> {
+        operand.split_whitespace().map(|san| {
+            self.position.parse_san(san)
+                         .ok_or_else(|| Error::InvalidMove(san.into()))
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fen::FenError;
+
+    #[test]
+    fn parse() {
+        let line = "2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - \
+                    bm Qg6; id \"WAC.001\";";
+        let epd: Epd = line.parse().unwrap();
+
+        assert_eq!(epd.position.player(), Color::White);
+        assert_eq!(epd.best_moves, [Move::normal(Square::G3, Square::G6)]);
+        assert!(epd.avoid_moves.is_empty());
+        assert_eq!(epd.id(), Some("WAC.001"));
+        assert_eq!(epd.operation("bm"), Some("Qg6"));
+        assert_eq!(epd.operations.len(), 2);
+
+        let line = "4k3/8/8/8/8/8/8/4K2R w K - am O-O Kf1; c0 \"a; b\";";
+        let epd: Epd = line.parse().unwrap();
+        assert_eq!(epd.avoid_moves.len(), 2);
+        assert_eq!(epd.operation("c0"), Some("a; b"));
+    }
+
+    #[test]
+    fn parse_error() {
+        let pos = "4k3/8/8/8/8/8/8/4K3 w - -";
+        let err = |s: &str| s.parse::<Epd>().err().unwrap();
+
+        match err("4k3/8/8/8/8/8/8/4K3 w -") {
+            Error::Position(err) => assert_eq!(err.fen_kind(), Some(FenError::BadEnPassant)),
+            err => panic!("unexpected error {:?}", err),
+        }
+        assert_eq!(err(&format!("{} bm Kd1", pos)), Error::Unterminated("bm".into()));
+        assert_eq!(err(&format!("{} id \"x;", pos)), Error::UnterminatedString);
+        assert_eq!(err(&format!("{} bm Ke3;", pos)), Error::InvalidMove("Ke3".into()));
+    }
+}
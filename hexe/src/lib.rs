@@ -135,7 +135,10 @@ mod table;
 mod util;
 mod zero;
 
+pub mod book;
 pub mod engine;
+pub mod epd;
+pub mod pgn;
 pub mod position;
 pub mod prelude;
 pub mod zobrist;
@@ -0,0 +1,306 @@
+//! [Portable Game Notation][pgn] support.
+//!
+//! [pgn]: https://en.wikipedia.org/wiki/Portable_Game_Notation
+
+use std::error;
+use std::fmt;
+
+use prelude::*;
+
+/// The maximum number of characters in a line of movetext.
+const MAX_LINE_LEN: usize = 80;
+
+/// The tokens that mark the end of a game.
+static RESULTS: [&str; 4] = ["1-0", "0-1", "1/2-1/2", "*"];
+
+/// An error returned when reading movetext fails.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// A `{` comment is not closed by a `}`.
+    UnterminatedComment,
+    /// A token is not a legal move in the position it is played from.
+    InvalidMove(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::UnterminatedComment => f.write_str("unterminated comment"),
+            Error::InvalidMove(ref san) => write!(f, "invalid move {:?}", san),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::UnterminatedComment => "unterminated comment",
+            Error::InvalidMove(_)      => "invalid move",
+        }
+    }
+}
+
+/// Appends the movetext for `moves` played from `start` to `out`.
+///
+/// Each move is written in [Standard Algebraic Notation][san], preceded by its
+/// move number when played by White. Numbering starts from the fullmove number
+/// of `start`, and if Black moves first, the first move is numbered with `...`
+/// instead. Lines are wrapped to fit within 80 columns.
+///
+/// Each move must be legal in the position that it is played from.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use hexe::pgn;
+/// use hexe::prelude::*;
+///
+/// let moves = [
+///     Move::normal(Square::E2, Square::E4),
+///     Move::normal(Square::E7, Square::E5),
+///     Move::normal(Square::G1, Square::F3),
+/// ];
+///
+/// let mut movetext = String::new();
+/// pgn::write_movetext(&Position::default(), &moves, &mut movetext);
+///
+/// assert_eq!(movetext, "1. e4 e5 2. Nf3");
+/// ```
+///
+/// [san]: https://en.wikipedia.org/wiki/Algebraic_notation_(chess)
+pub fn write_movetext(start: &Position, moves: &[Move], out: &mut String) {
+    let mut pos      = start.clone();
+    let mut number   = start.fullmoves();
+    let mut line_len = 0;
+
+    let mut push = |token: &str, out: &mut String| {
+        if line_len != 0 {
+            if line_len + 1 + token.len() > MAX_LINE_LEN {
+                out.push('\n');
+                line_len = 0;
+            } else {
+                out.push(' ');
+                line_len += 1;
+            }
+        }
+        out.push_str(token);
+        line_len += token.len();
+    };
+
+    for (i, &mv) in moves.iter().enumerate() {
+        match pos.player() {
+            Color::White => push(&format!("{}.", number), out),
+            Color::Black => {
+                if i == 0 {
+                    push(&format!("{}...", number), out);
+                }
+                number += 1;
+            },
+        }
+        push(&pos.san(mv), out);
+        pos = pos.play(mv);
+    }
+}
+
+/// Reads the moves of the movetext in `text` played from `start`.
+///
+/// Each move is resolved against the position it is played from with
+/// [`Position::parse_san`]. Move numbers, `{}` and `;` comments, and numeric
+/// annotation glyphs (e.g. `$1`) are skipped. Reading stops at a game result,
+/// such as `1-0` or `*`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use hexe::pgn;
+/// use hexe::prelude::*;
+///
+/// let text  = "1. e4 {best by test} e5 $1 2. Nf3 1-0";
+/// let moves = pgn::read_movetext(&Position::default(), text).unwrap();
+///
+/// assert_eq!(moves, [
+///     Move::normal(Square::E2, Square::E4),
+///     Move::normal(Square::E7, Square::E5),
+///     Move::normal(Square::G1, Square::F3),
+/// ]);
+/// ```
+///
+/// [`Position::parse_san`]: ../position/struct.Position.html#method.parse_san
+pub fn read_movetext(start: &Position, text: &str) -> Result<Vec<Move>, Error> {
+    let mut pos   = start.clone();
+    let mut moves = Vec::new();
+    let mut rest  = text;
+
+    loop {
+        rest = rest.trim_left();
+        if rest.is_empty() {
+            break;
+        }
+
+        if rest.starts_with('{') {
+            match rest.find('}') {
+                Some(end) => rest = &rest[(end + 1)..],
+                None => return Err(Error::UnterminatedComment),
+            }
+            continue;
+        }
+        if rest.starts_with(';') {
+            rest = rest.find('\n').map_or("", |end| &rest[end..]);
+            continue;
+        }
+
+        let end = rest.find(|ch: char| {
+            ch.is_whitespace() || ch == '{' || ch == ';'
+        }).unwrap_or(rest.len());
+        let (token, tail) = rest.split_at(end);
+        rest = tail;
+
+        if RESULTS.contains(&token) {
+            break;
+        }
+        if token.starts_with('$') {
+            continue;
+        }
+
+        // Only digits followed by a '.' make up a move number, since castles
+        // may be written with zeros, such as `0-0`
+        let digits = token.bytes().take_while(u8::is_ascii_digit).count();
+        let token = if token[digits..].starts_with('.') {
+            token[digits..].trim_left_matches('.')
+        } else {
+            token
+        };
+        if token.is_empty() {
+            continue;
+        }
+
+        match pos.parse_san(token) {
+            Some(mv) => {
+                pos = pos.play(mv);
+                moves.push(mv);
+            },
+            None => return Err(Error::InvalidMove(token.into())),
+        }
+    }
+
+    Ok(moves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn movetext(start: &Position, moves: &[Move]) -> String {
+        let mut out = String::new();
+        write_movetext(start, moves, &mut out);
+        out
+    }
+
+    #[test]
+    fn scholars_mate() {
+        let moves = [
+            Move::normal(Square::E2, Square::E4),
+            Move::normal(Square::E7, Square::E5),
+            Move::normal(Square::F1, Square::C4),
+            Move::normal(Square::B8, Square::C6),
+            Move::normal(Square::D1, Square::H5),
+            Move::normal(Square::G8, Square::F6),
+            Move::normal(Square::H5, Square::F7),
+        ];
+        assert_eq!(
+            movetext(&Position::default(), &moves),
+            "1. e4 e5 2. Bc4 Nc6 3. Qh5 Nf6 4. Qxf7#"
+        );
+        assert_eq!(movetext(&Position::default(), &[]), "");
+    }
+
+    #[test]
+    fn read() {
+        let text = "
+            [Event \"ignored\"]
+            1. e4 e5 {A classical reply} 2. Bc4 Nc6 $2 ; a mistake
+            3. Qh5 Nf6?? 4.Qxf7# 1-0 5. Nf3
+        ";
+        let text = &text[text.find(']').unwrap() + 1..];
+
+        let moves = read_movetext(&Position::default(), text).unwrap();
+        assert_eq!(moves.len(), 7);
+        assert_eq!(moves[6], Move::normal(Square::H5, Square::F7));
+        assert_eq!(movetext(&Position::default(), &moves),
+                   "1. e4 e5 2. Bc4 Nc6 3. Qh5 Nf6 4. Qxf7#");
+
+        let pos = Position::default();
+        assert_eq!(read_movetext(&pos, "1... e4 *"), Ok(vec![moves[0]]));
+        assert_eq!(read_movetext(&pos, "1. e4 {oops"), Err(Error::UnterminatedComment));
+        assert_eq!(read_movetext(&pos, "1. e4 e4"), Err(Error::InvalidMove("e4".into())));
+    }
+
+    #[test]
+    fn round_trip() {
+        let shuffle = [
+            Move::normal(Square::B1, Square::C3),
+            Move::normal(Square::B8, Square::C6),
+            Move::normal(Square::C3, Square::B1),
+            Move::normal(Square::C6, Square::B8),
+        ];
+        let moves: Vec<Move> = shuffle.iter().cloned().cycle().take(60).collect();
+
+        let pos  = Position::default();
+        let text = movetext(&pos, &moves);
+        assert_eq!(read_movetext(&pos, &text), Ok(moves));
+    }
+
+    #[test]
+    fn black_to_move() {
+        let fen = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1";
+        let pos = Position::from_fen(fen).unwrap();
+
+        let moves = [
+            Move::normal(Square::E7, Square::E5),
+            Move::normal(Square::G1, Square::F3),
+            Move::normal(Square::B8, Square::C6),
+        ];
+        assert_eq!(movetext(&pos, &moves), "1... e5 2. Nf3 Nc6");
+
+        let fen = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 23";
+        let pos = Position::from_fen(fen).unwrap();
+        assert_eq!(movetext(&pos, &moves), "23... e5 24. Nf3 Nc6");
+    }
+
+    #[test]
+    fn read_zero_castles() {
+        let fen = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 5";
+        let pos = Position::from_fen(fen).unwrap();
+
+        let moves = [
+            Move::castle(Right::WhiteKing),
+            Move::castle(Right::BlackQueen),
+        ];
+        assert_eq!(read_movetext(&pos, "5. 0-0 0-0-0 *"), Ok(moves.to_vec()));
+        assert_eq!(read_movetext(&pos, "5.0-0 5...0-0-0"), Ok(moves.to_vec()));
+    }
+
+    #[test]
+    fn wrapping() {
+        let shuffle = [
+            Move::normal(Square::G1, Square::F3),
+            Move::normal(Square::G8, Square::F6),
+            Move::normal(Square::F3, Square::G1),
+            Move::normal(Square::F6, Square::G8),
+        ];
+        let moves: Vec<Move> = shuffle.iter().cloned().cycle().take(100).collect();
+
+        let text = movetext(&Position::default(), &moves);
+        assert!(text.lines().count() > 1);
+        for line in text.lines() {
+            assert!(line.len() <= MAX_LINE_LEN, "{:?}", line);
+            assert!(!line.starts_with(' ') && !line.ends_with(' '));
+        }
+        assert!(text.ends_with("50. Ng1 Ng8"));
+        assert_eq!(text.split_whitespace().count(), 150);
+    }
+}
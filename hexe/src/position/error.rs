@@ -0,0 +1,118 @@
+use super::*;
+use core::fen::{FenError, FromStrError};
+use std::error;
+use std::fmt;
+
+/// An error returned when attempting to construct an invalid [`Position`].
+///
+/// [`Position`]: struct.Position.html
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// The color does not have exactly one king.
+    KingCount(Color),
+    /// A pawn is placed on the first or last rank.
+    PawnRank(Square),
+    /// The player who is not to move is in check.
+    OpponentInCheck,
+    /// The en passant square can't be the target of a double pawn push.
+    EnPassant(Square),
+    /// The king or rook for the castle right is not on its initial square.
+    Rights(Right),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::KingCount(color) => {
+                write!(f, "{} does not have exactly one king", color)
+            },
+            Error::PawnRank(sq) => {
+                write!(f, "pawn placed on back rank at {}", sq)
+            },
+            Error::OpponentInCheck => {
+                f.write_str("player not to move is in check")
+            },
+            Error::EnPassant(sq) => {
+                write!(f, "invalid en passant square {}", sq)
+            },
+            Error::Rights(right) => {
+                write!(f, "castle right {:?} has no king and rook", right)
+            },
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::KingCount(_)     => "invalid king count",
+            Error::PawnRank(_)      => "pawn on back rank",
+            Error::OpponentInCheck  => "opponent in check",
+            Error::EnPassant(_)     => "invalid en passant square",
+            Error::Rights(_)        => "invalid castle rights",
+        }
+    }
+}
+
+/// An error returned by [`Position::from_fen`].
+///
+/// [`Position::from_fen`]: struct.Position.html#method.from_fen
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FromFenError {
+    /// The string is not valid FEN.
+    Fen(FromStrError),
+    /// The FEN describes an invalid position.
+    Position(Error),
+}
+
+impl FromFenError {
+    /// Returns the kind of FEN error, if the string could not be parsed.
+    #[inline]
+    pub fn fen_kind(&self) -> Option<FenError> {
+        match *self {
+            FromFenError::Fen(err) => Some(err.kind()),
+            FromFenError::Position(_) => None,
+        }
+    }
+}
+
+impl From<FromStrError> for FromFenError {
+    #[inline]
+    fn from(err: FromStrError) -> FromFenError {
+        FromFenError::Fen(err)
+    }
+}
+
+impl From<Error> for FromFenError {
+    #[inline]
+    fn from(err: Error) -> FromFenError {
+        FromFenError::Position(err)
+    }
+}
+
+impl fmt::Display for FromFenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FromFenError::Fen(ref err) => {
+                write!(f, "{} ({})", err.kind(), err)
+            },
+            FromFenError::Position(ref err) => err.fmt(f),
+        }
+    }
+}
+
+impl error::Error for FromFenError {
+    fn description(&self) -> &str {
+        match *self {
+            FromFenError::Fen(_)      => "invalid FEN string",
+            FromFenError::Position(_) => "invalid position",
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn error::Error> {
+        match *self {
+            FromFenError::Fen(ref err) => Some(err),
+            FromFenError::Position(ref err) => Some(err),
+        }
+    }
+}
@@ -1,9 +1,18 @@
 //! A chess game state position.
 
 use core::board::{MultiBoard, PieceMap};
+use core::fen::Fen;
 use core::misc::Contained;
 use core::mv::{self, MoveVec};
 use prelude::*;
+#[cfg(feature = "serde")]
+use serde::*;
+use std::fmt;
+use std::sync::Arc;
+use zobrist;
+
+mod error;
+pub use self::error::*;
 
 mod state;
 pub use self::state::*;
@@ -11,6 +20,9 @@ pub use self::state::*;
 mod mv_gen;
 pub use self::mv_gen::*;
 
+pub mod san;
+mod see;
+
 #[cfg(all(test, nightly))]
 mod benches;
 
@@ -41,6 +53,18 @@ impl PartialEq for Position {
 
 impl Eq for Position {}
 
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let en_passant = match self.en_passant() {
+            Some(sq) => sq.as_str_lower(),
+            None => "-",
+        };
+        writeln!(f, "{}", self.pieces)?;
+        write!(f, "{} to move, castling: {}, en passant: {}",
+               self.player, self.rights(), en_passant)
+    }
+}
+
 impl Default for Position {
     #[inline]
     fn default() -> Position {
@@ -48,6 +72,31 @@ impl Default for Position {
     }
 }
 
+/// Serializes as the position's FEN string, which is canonical for the pieces,
+/// player, castle rights, en passant square, and move counters. The move
+/// history is not serialized.
+#[cfg(feature = "serde")]
+impl Serialize for Position {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        self.to_fen().serialize(ser)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Position {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        let fen = Fen::deserialize(de)?;
+        Position::from_parts(
+            fen.pieces,
+            fen.color,
+            fen.castling,
+            fen.en_passant,
+            fen.halfmoves,
+            fen.fullmoves,
+        ).map_err(de::Error::custom)
+    }
+}
+
 impl Position {
     /// The starting position for standard chess.
     pub const STANDARD: Position = Position {
@@ -57,6 +106,210 @@ impl Position {
         player: Color::White,
     };
 
+    /// Creates a new position from its pieces, the player to move, castle
+    /// rights, and en passant square.
+    ///
+    /// The `MultiBoard` is derived from `pieces`. Basic legality checks are
+    /// performed, such as each player having exactly one king and the player
+    /// not to move not being in check.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe::board::PieceMap;
+    /// use hexe::prelude::*;
+    ///
+    /// let mut pieces = PieceMap::new();
+    /// pieces.insert(Square::E1, Piece::WhiteKing);
+    /// pieces.insert(Square::E8, Piece::BlackKing);
+    ///
+    /// let pos = Position::new(pieces, Color::White, Rights::EMPTY, None);
+    /// assert!(pos.is_ok());
+    /// ```
+    pub fn new(
+        pieces: PieceMap,
+        player: Color,
+        rights: Rights,
+        en_passant: Option<Square>,
+    ) -> Result<Position, Error> {
+        let board = MultiBoard::from(&pieces);
+
+        for color in Color::ALL {
+            if board.count(Piece::new(Role::King, color)) != 1 {
+                return Err(Error::KingCount(color));
+            }
+        }
+
+        let back_ranks = BitBoard::from(Rank::One) | Rank::Eight;
+        if let Some(sq) = (board.bits(Role::Pawn) & back_ranks).lsb() {
+            return Err(Error::PawnRank(sq));
+        }
+
+        for right in rights {
+            let color = right.color();
+            let king  = right.king_origin();
+            let rook  = right.rook_origin();
+
+            if !board.contains(king, Piece::new(Role::King, color)) ||
+               !board.contains(rook, Piece::new(Role::Rook, color)) {
+                return Err(Error::Rights(right));
+            }
+        }
+
+        if let Some(ep) = en_passant {
+            // The opponent's pawn must have just passed over `ep`
+            let opponent = !player;
+            let rank = match opponent {
+                Color::White => Rank::Three,
+                Color::Black => Rank::Six,
+            };
+            let pawn = BitBoard::from(ep).advance(opponent);
+            let src  = BitBoard::from(ep).retreat(opponent);
+
+            if ep.rank() != rank
+                || board.all_bits().intersects(ep | src)
+                || !board.contains(pawn, Piece::new(Role::Pawn, opponent))
+            {
+                return Err(Error::EnPassant(ep));
+            }
+        }
+
+        let mut pos = Position {
+            state: State {
+                prev: None,
+                en_passant,
+                rights,
+                key: 0,
+                halfmoves: 0,
+                fullmoves: 1,
+            },
+            pieces,
+            board,
+            player,
+        };
+
+        let opponent = pos.opponent();
+        if pos.board.is_attacked(pos.king_square(opponent), opponent) {
+            return Err(Error::OpponentInCheck);
+        }
+
+        pos.state.key = pos.zobrist();
+
+        Ok(pos)
+    }
+
+    /// Creates a new position from all of its parts, including the halfmove
+    /// clock and fullmove number.
+    ///
+    /// This performs the same checks as [`new`](#method.new), which leaves
+    /// both counters at their initial values.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe::board::PieceMap;
+    /// use hexe::position::Error;
+    /// use hexe::prelude::*;
+    ///
+    /// let mut pieces = PieceMap::new();
+    /// pieces.insert(Square::E1, Piece::WhiteKing);
+    /// pieces.insert(Square::E8, Piece::BlackKing);
+    ///
+    /// let pos = Position::from_parts(pieces.clone(), Color::Black, Rights::EMPTY, None, 3, 20)
+    ///     .ok()
+    ///     .unwrap();
+    /// assert_eq!(pos.halfmoves(), 3);
+    /// assert_eq!(pos.fullmoves(), 20);
+    ///
+    /// pieces.remove(Square::E8);
+    /// let err = Position::from_parts(pieces, Color::White, Rights::EMPTY, None, 0, 1).err();
+    /// assert_eq!(err, Some(Error::KingCount(Color::Black)));
+    /// ```
+    pub fn from_parts(
+        pieces: PieceMap,
+        player: Color,
+        rights: Rights,
+        en_passant: Option<Square>,
+        halfmoves: u32,
+        fullmoves: u32,
+    ) -> Result<Position, Error> {
+        let mut pos = Position::new(pieces, player, rights, en_passant)?;
+        pos.state.halfmoves = halfmoves;
+        pos.state.fullmoves = fullmoves;
+        Ok(pos)
+    }
+
+    /// Creates a new position from a [FEN][fen] string.
+    ///
+    /// Failure to parse `fen` is reported with the offending field, while a
+    /// well-formed string describing an illegal position is reported with the
+    /// same error as [`new`](#method.new).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe::fen::FenError;
+    /// use hexe::position::{Error, FromFenError};
+    /// use hexe::prelude::*;
+    ///
+    /// let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+    /// assert!(Position::from_fen(fen).is_ok());
+    ///
+    /// let err = Position::from_fen("8/8/8/8/8/8/8/8 w - - 0").err().unwrap();
+    /// assert_eq!(err.fen_kind(), Some(FenError::WrongFieldCount));
+    ///
+    /// let err = Position::from_fen("8/8/8/8/8/8/8/8 w - - 0 1").err().unwrap();
+    /// assert_eq!(err, FromFenError::Position(Error::KingCount(Color::White)));
+    /// ```
+    ///
+    /// [fen]: https://en.wikipedia.org/wiki/Forsyth%E2%80%93Edwards_Notation
+    pub fn from_fen(fen: &str) -> Result<Position, FromFenError> {
+        let fen: Fen = fen.parse()?;
+        let pos = Position::from_parts(
+            fen.pieces,
+            fen.color,
+            fen.castling,
+            fen.en_passant,
+            fen.halfmoves,
+            fen.fullmoves,
+        )?;
+        Ok(pos)
+    }
+
+    /// Returns the [FEN][fen] representation of `self`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe::prelude::*;
+    ///
+    /// let pos = Position::default().play(Move::normal(Square::E2, Square::E4));
+    /// assert_eq!(
+    ///     pos.to_fen().to_string(),
+    ///     "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+    /// );
+    /// ```
+    ///
+    /// [fen]: https://en.wikipedia.org/wiki/Forsyth%E2%80%93Edwards_Notation
+    pub fn to_fen(&self) -> Fen {
+        Fen {
+            pieces: self.pieces.clone(),
+            color: self.player,
+            castling: self.rights(),
+            en_passant: self.en_passant(),
+            halfmoves: self.halfmoves(),
+            fullmoves: self.fullmoves(),
+        }
+    }
+
     /// Returns the inner piece map.
     #[inline]
     pub fn pieces(&self) -> &PieceMap {
@@ -87,6 +340,82 @@ impl Position {
         MoveGen { pos: self, buf: moves }
     }
 
+    /// Returns all legal moves for this position.
+    ///
+    /// For performance-sensitive code, [`gen`](#method.gen) allows for reusing
+    /// an existing buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hexe::position::Position;
+    ///
+    /// let pos = Position::default();
+    /// assert_eq!(pos.legal_moves().len(), 20);
+    /// ```
+    #[inline]
+    pub fn legal_moves(&self) -> MoveVec {
+        let mut moves = MoveVec::new();
+        self.gen(&mut moves).legal();
+        moves
+    }
+
+    /// Appends all legal moves for this position to `out`.
+    ///
+    /// This is useful when a growable vector is preferred over the inline
+    /// buffer of [`MoveVec`](../mv/struct.MoveVec.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hexe::position::Position;
+    ///
+    /// let pos = Position::default();
+    /// let mut moves = Vec::new();
+    ///
+    /// pos.generate_into(&mut moves);
+    /// assert_eq!(moves.len(), 20);
+    /// ```
+    pub fn generate_into(&self, out: &mut Vec<Move>) {
+        out.extend_from_slice(&self.legal_moves());
+    }
+
+    /// Returns the number of leaf nodes in the tree of legal moves from
+    /// `self` to `depth`, a [perft] count used for verifying move generation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hexe::position::Position;
+    ///
+    /// let pos = Position::default();
+    /// assert_eq!(pos.perft(1), 20);
+    /// assert_eq!(pos.perft(2), 400);
+    /// ```
+    ///
+    /// [perft]: https://www.chessprogramming.org/Perft
+    pub fn perft(&self, depth: u32) -> u64 {
+        match depth {
+            0 => 1,
+            1 => self.legal_moves().len() as u64,
+            _ => self.legal_moves().iter().map(|&mv| {
+                self.play(mv).perft(depth - 1)
+            }).sum(),
+        }
+    }
+
+    /// Returns the [`perft`](#method.perft) count to `depth` following each
+    /// legal move of `self`.
+    ///
+    /// Comparing these against a known-good engine narrows down which moves
+    /// lead to a move generation bug. A `depth` of 0 is treated as 1.
+    pub fn perft_divide(&self, depth: u32) -> Vec<(Move, u64)> {
+        let depth = depth.saturating_sub(1);
+        self.legal_moves().iter().map(|&mv| {
+            (mv, self.play(mv).perft(depth))
+        }).collect()
+    }
+
     /// Returns whether the move is legal for this position.
     #[inline]
     pub fn is_legal<M: Into<Move>>(&self, mv: M) -> bool {
@@ -146,6 +475,206 @@ impl Position {
         false
     }
 
+    /// Returns the position that results from playing `mv`, which is expected
+    /// to be legal for `self`.
+    ///
+    /// The current state is kept as the history of the returned position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hexe::prelude::*;
+    ///
+    /// let pos  = Position::default();
+    /// let next = pos.play(Move::normal(Square::E2, Square::E4));
+    ///
+    /// assert_eq!(next.player(), Color::Black);
+    /// assert_eq!(next.en_passant(), Some(Square::E3));
+    /// ```
+    pub fn play(&self, mv: Move) -> Position {
+        use self::mv::Matches;
+
+        let src    = mv.src();
+        let dst    = mv.dst();
+        let player = self.player;
+
+        let moved = self.pieces.role_at(src).expect("no piece to move");
+        let captured = match mv.matches() {
+            Matches::EnPassant(_) => Some(Role::Pawn),
+            Matches::Castle(_)    => None,
+            _ => self.pieces.role_at(dst),
+        };
+
+        let keys    = &zobrist::KEYS;
+        let mut key = self.state.key ^ keys.color;
+        let mover   = Piece::new(moved, player);
+
+        let mut pieces = self.pieces.clone();
+        match mv.matches() {
+            Matches::Normal(_) => {
+                if let Some(&pc) = pieces.get(dst) {
+                    key ^= keys.piece(pc, dst);
+                }
+                key ^= keys.piece(mover, src) ^ keys.piece(mover, dst);
+                pieces.capture(src, dst);
+            },
+            Matches::Castle(mv) => {
+                let right = mv.right();
+                let rook  = Piece::new(Role::Rook, player);
+                key ^= keys.piece(mover, src) ^ keys.piece(mover, dst);
+                key ^= keys.piece(rook, right.rook_origin());
+                key ^= keys.piece(rook, right.rook_destination());
+                pieces.castle(right);
+            },
+            Matches::Promotion(mv) => {
+                if let Some(&pc) = pieces.get(dst) {
+                    key ^= keys.piece(pc, dst);
+                }
+                let promotion = Piece::new(mv.piece().into(), player);
+                key ^= keys.piece(mover, src) ^ keys.piece(promotion, dst);
+                pieces.capture(src, dst);
+                pieces.insert(dst, promotion);
+            },
+            Matches::EnPassant(mv) => {
+                let pawn = Piece::new(Role::Pawn, !player);
+                key ^= keys.piece(mover, src) ^ keys.piece(mover, dst);
+                key ^= keys.piece(pawn, mv.capture());
+                pieces.en_passant(src, dst);
+            },
+        };
+
+        let mut board = self.board.clone();
+        board.apply(mv, player, moved, captured);
+
+        // Moving the king forfeits both of its rights, and moving or capturing
+        // on a rook square forfeits that right
+        let mut rights = self.rights();
+        if moved == Role::King {
+            rights.clear_color(player);
+        }
+        for right in rights {
+            let squares = right.king_origin() | right.rook_origin();
+            if squares.intersects(src | dst) {
+                rights -= right;
+            }
+        }
+
+        let halfmoves = if moved == Role::Pawn || captured.is_some() {
+            0
+        } else {
+            self.state.halfmoves + 1
+        };
+
+        let en_passant = if moved == Role::Pawn && src.double_push(player) == Some(dst) {
+            src.pawn_push(player)
+        } else {
+            None
+        };
+
+        if !rights.toggled(self.rights()).is_empty() {
+            key ^= keys.castle(self.rights()) ^ keys.castle(rights);
+        }
+        if let Some(ep) = self.en_passant() {
+            key ^= keys.en_passant(ep.file());
+        }
+        if let Some(ep) = en_passant {
+            key ^= keys.en_passant(ep.file());
+        }
+
+        let next = Position {
+            state: State {
+                prev: Some(Arc::new(self.state.clone())),
+                en_passant,
+                rights,
+                key,
+                halfmoves,
+                fullmoves: self.next_fullmoves(),
+            },
+            pieces,
+            board,
+            player: !player,
+        };
+        debug_assert_eq!(next.key(), next.zobrist(), "bad key after {:?}", mv);
+        next
+    }
+
+    /// Passes the turn to the opponent without moving a piece, as with
+    /// [`Move::null`](../mv/struct.Move.html#method.null).
+    ///
+    /// The en passant square is cleared and the Zobrist key is updated. The
+    /// current state is kept as history, to be restored by
+    /// [`unmake_null`](#method.unmake_null).
+    ///
+    /// A null move is illegal when the player to move is in check, since it
+    /// would leave the king in check. It is meant for search techniques such as
+    /// null move pruning, which must not make it in that case.
+    pub fn make_null(&mut self) {
+        debug_assert!(!self.is_check(), "null move made while in check");
+
+        let keys    = &zobrist::KEYS;
+        let mut key = self.state.key ^ keys.color;
+        if let Some(ep) = self.en_passant() {
+            key ^= keys.en_passant(ep.file());
+        }
+
+        self.state = State {
+            prev: Some(Arc::new(self.state.clone())),
+            en_passant: None,
+            rights: self.rights(),
+            key,
+            halfmoves: self.state.halfmoves + 1,
+            fullmoves: self.next_fullmoves(),
+        };
+        self.player = !self.player;
+    }
+
+    /// Undoes the last call to [`make_null`](#method.make_null), restoring
+    /// the previous player, en passant square, and Zobrist key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` has no previous state. Calling this after anything
+    /// other than `make_null` results in an invalid position.
+    pub fn unmake_null(&mut self) {
+        let prev = self.state.prev.take().expect("no null move to unmake");
+        self.state  = Arc::try_unwrap(prev).unwrap_or_else(|prev| (*prev).clone());
+        self.player = !self.player;
+    }
+
+    /// Returns the [Zobrist hash][wiki] of `self`.
+    ///
+    /// Unlike [`zobrist`](#method.zobrist), this does not compute the hash but
+    /// returns the one kept up to date as moves are made.
+    ///
+    /// [wiki]: https://en.wikipedia.org/wiki/Zobrist_hashing
+    #[inline]
+    pub fn key(&self) -> u64 {
+        self.state.key
+    }
+
+    /// Computes the [Zobrist hash][wiki] of `self` from scratch.
+    ///
+    /// The hash covers the pieces, the player to move, castle rights, and the
+    /// en passant file. Positions created via [`play`](#method.play) keep
+    /// track of it incrementally, which always equals this result.
+    ///
+    /// [wiki]: https://en.wikipedia.org/wiki/Zobrist_hashing
+    pub fn zobrist(&self) -> u64 {
+        let keys = &zobrist::KEYS;
+        let mut key = keys.castle(self.rights());
+
+        for (square, &piece) in self.pieces() {
+            key ^= keys.piece(piece, square);
+        }
+        if let Some(ep) = self.en_passant() {
+            key ^= keys.en_passant(ep.file());
+        }
+        if self.player() == Color::Black {
+            key ^= keys.color;
+        }
+        key
+    }
+
     /// Returns whether `self` contains the value.
     #[inline]
     pub fn contains<'a, T: Contained<&'a Self>>(&'a self, value: T) -> bool {
@@ -176,6 +705,226 @@ impl Position {
         self.board().bits(self.opponent())
     }
 
+    /// Returns whether the player to move is in check.
+    #[inline]
+    pub fn is_check(&self) -> bool {
+        let player = self.player();
+        self.board.is_attacked(self.king_square(player), player)
+    }
+
+    /// Returns whether playing `mv`, which is expected to be legal for `self`,
+    /// puts the opponent in check.
+    ///
+    /// This is determined without making the move. A check is given directly
+    /// by the moved piece (or the rook when castling) from its destination, or
+    /// is discovered by a slider whose line to the king is opened by the move.
+    /// For en passant, both the moved and the captured pawns may open a line.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe::prelude::*;
+    ///
+    /// let pos = Position::default()
+    ///     .play(Move::normal(Square::E2, Square::E4))
+    ///     .play(Move::normal(Square::F7, Square::F6));
+    ///
+    /// assert!(pos.gives_check(Move::normal(Square::D1, Square::H5)));
+    /// assert!(!pos.gives_check(Move::normal(Square::D1, Square::G4)));
+    /// ```
+    pub fn gives_check(&self, mv: Move) -> bool {
+        use self::mv::Matches;
+
+        let player = self.player();
+        let king   = self.king_square(!player);
+        let board  = self.board();
+        let src    = mv.src();
+        let dst    = mv.dst();
+
+        // The square and piece that may give a direct check, along with the
+        // squares vacated and filled by the move
+        let (checker, piece, vacated, filled) = match mv.matches() {
+            Matches::Castle(mv) => {
+                let right = mv.right();
+                let rook  = right.rook_destination();
+                let vacated = right.king_origin() | right.rook_origin();
+                let filled  = right.king_destination() | rook;
+                (rook, Piece::new(Role::Rook, player), vacated, filled)
+            },
+            Matches::Promotion(mv) => {
+                let piece = Piece::new(mv.piece().into(), player);
+                (dst, piece, src.into(), dst.into())
+            },
+            Matches::EnPassant(mv) => {
+                let piece = Piece::new(Role::Pawn, player);
+                (dst, piece, src | mv.capture(), dst.into())
+            },
+            Matches::Normal(_) => match self.pieces.get(src) {
+                Some(&piece) => (dst, piece, src.into(), dst.into()),
+                None => return false,
+            },
+        };
+
+        let occupied = (board.all_bits() - vacated) | filled;
+        if checker.attacks(piece, occupied).contains(king) {
+            return true;
+        }
+
+        // Sliders that remain in place after the move
+        let ours    = board.bits(player) - vacated;
+        let queens  = board.bits(Role::Queen);
+        let bishops = (board.bits(Role::Bishop) | queens) & ours;
+        let rooks   = (board.bits(Role::Rook)   | queens) & ours;
+
+        king.bishop_attacks(occupied).intersects(bishops) ||
+        king.rook_attacks(occupied).intersects(rooks)
+    }
+
+    /// Returns the pieces of `color` that are absolutely pinned to its king.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe::prelude::*;
+    ///
+    /// let pos = Position::default()
+    ///     .play(Move::normal(Square::D2, Square::D4))
+    ///     .play(Move::normal(Square::E7, Square::E6))
+    ///     .play(Move::normal(Square::B1, Square::C3))
+    ///     .play(Move::normal(Square::F8, Square::B4));
+    ///
+    /// assert_eq!(pos.pinned(Color::White),  Square::C3.into());
+    /// assert_eq!(pos.pinners(Color::White), Square::B4.into());
+    /// ```
+    pub fn pinned(&self, color: Color) -> BitBoard {
+        self.pins(color).0
+    }
+
+    /// Returns the pieces of the opponent of `color` that pin pieces of
+    /// `color` to its king.
+    pub fn pinners(&self, color: Color) -> BitBoard {
+        self.pins(color).1
+    }
+
+    /// Returns the pinned pieces of `color` and the pieces pinning them.
+    fn pins(&self, color: Color) -> (BitBoard, BitBoard) {
+        let board    = self.board();
+        let king     = self.king_square(color);
+        let occupied = board.all_bits();
+        let ours     = board.bits(color);
+
+        // Enemy sliders that would attack the king on an empty board
+        let theirs  = board.bits(!color);
+        let queens  = board.bits(Role::Queen);
+        let bishops = (board.bits(Role::Bishop) | queens) & theirs;
+        let rooks   = (board.bits(Role::Rook)   | queens) & theirs;
+        let snipers = (king.bishop_attacks(BitBoard::EMPTY) & bishops) |
+                      (king.rook_attacks(BitBoard::EMPTY)   & rooks);
+
+        let mut pinned  = BitBoard::EMPTY;
+        let mut pinners = BitBoard::EMPTY;
+        for sniper in snipers {
+            let blockers = king.between(sniper) & occupied;
+            if blockers.exactly_one() && blockers.intersects(ours) {
+                pinned  |= blockers;
+                pinners |= sniper;
+            }
+        }
+        (pinned, pinners)
+    }
+
+    /// Returns whether the player to move is in check and has no legal moves.
+    pub fn is_checkmate(&self) -> bool {
+        self.is_check() && !self.has_legal_moves()
+    }
+
+    /// Returns whether the player to move is not in check but has no legal
+    /// moves.
+    pub fn is_stalemate(&self) -> bool {
+        !self.is_check() && !self.has_legal_moves()
+    }
+
+    /// Returns whether the game is drawn by the [fifty-move rule][rule].
+    ///
+    /// This is the case when 100 halfmoves have been played without a capture
+    /// or pawn move, unless the last of them delivered checkmate.
+    ///
+    /// [rule]: https://en.wikipedia.org/wiki/Fifty-move_rule
+    pub fn is_fifty_move_draw(&self) -> bool {
+        self.state.halfmoves >= 100 && !self.is_checkmate()
+    }
+
+    /// Returns whether the current position has occurred for the third time
+    /// since the last capture or pawn move, drawing by
+    /// [threefold repetition][rule].
+    ///
+    /// Only positions reached via [`play`](#method.play) are considered.
+    ///
+    /// [rule]: https://en.wikipedia.org/wiki/Threefold_repetition
+    pub fn is_repetition_draw(&self) -> bool {
+        let key = self.state.key;
+        let mut count = 1;
+
+        // Repetitions can only occur with the same player to move, within the
+        // halfmoves since the last irreversible move
+        let mut state = &self.state;
+        for ply in 1..(self.state.halfmoves + 1) {
+            state = match state.prev() {
+                Some(prev) => prev,
+                None => break,
+            };
+            if ply % 2 == 0 && state.key == key {
+                count += 1;
+                if count == 3 {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Returns whether neither player has enough material to deliver
+    /// checkmate.
+    ///
+    /// This covers a lone king against a lone king, a king and a single knight
+    /// or bishop against a lone king, and a king and bishop against a king and
+    /// bishop where both bishops are on squares of the same color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hexe::position::Position;
+    ///
+    /// let pos = Position::from_fen("8/8/4k3/8/8/3BK3/8/8 w - - 0 1").ok().unwrap();
+    /// assert!(pos.is_insufficient_material());
+    /// assert!(!Position::default().is_insufficient_material());
+    /// ```
+    pub fn is_insufficient_material(&self) -> bool {
+        let board = self.board();
+        match board.len() {
+            2 => true,
+            3 => board.count(Role::Knight) + board.count(Role::Bishop) == 1,
+            4 => {
+                let bishops = board.bits(Role::Bishop);
+                match (bishops.lsb(), bishops.msb()) {
+                    (Some(a), Some(b)) if a != b => {
+                        board.count(Piece::WhiteBishop) == 1 && a.color() == b.color()
+                    },
+                    _ => false,
+                }
+            },
+            _ => false,
+        }
+    }
+
+    fn has_legal_moves(&self) -> bool {
+        !self.legal_moves().is_empty()
+    }
+
     /// Returns the en passant square.
     #[inline]
     pub fn en_passant(&self) -> Option<Square> {
@@ -188,6 +937,57 @@ impl Position {
         self.state.rights()
     }
 
+    /// Returns the number of halfmoves since the last capture or pawn move,
+    /// as used by the [fifty-move rule](#method.is_fifty_move_draw).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe::prelude::*;
+    ///
+    /// let pos = Position::default().play(Move::normal(Square::G1, Square::F3));
+    /// assert_eq!(pos.halfmoves(), 1);
+    ///
+    /// let pos = pos.play(Move::normal(Square::E7, Square::E5));
+    /// assert_eq!(pos.halfmoves(), 0);
+    /// ```
+    #[inline]
+    pub fn halfmoves(&self) -> u32 {
+        self.state.halfmoves()
+    }
+
+    /// Returns the fullmove number, which starts at 1 and is incremented after
+    /// each move by Black.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe::prelude::*;
+    ///
+    /// let pos = Position::default().play(Move::normal(Square::E2, Square::E4));
+    /// assert_eq!(pos.fullmoves(), 1);
+    ///
+    /// let pos = pos.play(Move::normal(Square::E7, Square::E5));
+    /// assert_eq!(pos.fullmoves(), 2);
+    /// ```
+    #[inline]
+    pub fn fullmoves(&self) -> u32 {
+        self.state.fullmoves()
+    }
+
+    /// Returns the fullmove number after the player to move makes a move.
+    #[inline]
+    fn next_fullmoves(&self) -> u32 {
+        match self.player {
+            Color::White => self.state.fullmoves,
+            Color::Black => self.state.fullmoves + 1,
+        }
+    }
+
     /// Returns the square where the color's king lies on.
     #[inline]
     pub fn king_square(&self, color: Color) -> Square {
@@ -248,4 +1048,509 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn generate_into() {
+        let mut moves = Vec::new();
+
+        let pos = Position::default();
+        pos.generate_into(&mut moves);
+        assert_eq!(moves.len(), 20);
+
+        let mut pieces = PieceMap::new();
+        pieces.insert(Square::A1, Piece::WhiteKing);
+        pieces.insert(Square::H8, Piece::BlackKing);
+
+        let pos = Position::new(pieces, Color::Black, Rights::EMPTY, None).unwrap();
+        moves.clear();
+        pos.generate_into(&mut moves);
+        assert_eq!(moves.len(), 3);
+
+        // Appends rather than overwrites
+        pos.generate_into(&mut moves);
+        assert_eq!(moves.len(), 6);
+    }
+
+    #[test]
+    fn new_endgame() {
+        let mut pieces = PieceMap::new();
+        pieces.insert(Square::E1, Piece::WhiteKing);
+        pieces.insert(Square::H1, Piece::WhiteRook);
+        pieces.insert(Square::E8, Piece::BlackKing);
+
+        let pos = Position::new(pieces.clone(), Color::White, Rights::EMPTY, None).unwrap();
+        assert!(pos.board() == &MultiBoard::from(&pieces));
+
+        let mut moves = MoveVec::new();
+        pos.gen(&mut moves).legal();
+
+        // 5 king moves and 9 rook moves
+        assert_eq!(moves.len(), 14);
+        assert!(moves.contains(&Move::normal(Square::H1, Square::H8)));
+        assert!(!moves.contains(&Move::castle(Right::WhiteKing)));
+
+        let res = Position::new(pieces.clone(), Color::White, Rights::WHITE_QUEEN, None);
+        assert_eq!(res.err(), Some(Error::Rights(Right::WhiteQueen)));
+
+        pieces.remove(Square::E8);
+        let res = Position::new(pieces, Color::White, Rights::EMPTY, None);
+        assert_eq!(res.err(), Some(Error::KingCount(Color::Black)));
+    }
+
+    #[test]
+    fn play() {
+        let mut pieces = PieceMap::new();
+        pieces.insert(Square::E1, Piece::WhiteKing);
+        pieces.insert(Square::A1, Piece::WhiteRook);
+        pieces.insert(Square::H1, Piece::WhiteRook);
+        pieces.insert(Square::E8, Piece::BlackKing);
+        pieces.insert(Square::H8, Piece::BlackRook);
+
+        let rights = Rights::WHITE | Rights::BLACK_KING;
+        let pos = Position::new(pieces, Color::White, rights, None).unwrap();
+
+        let next = pos.play(Move::normal(Square::H1, Square::H8));
+        assert_eq!(next.rights(), Rights::WHITE_QUEEN);
+        assert_eq!(next.pieces().get(Square::H8), Some(&Piece::WhiteRook));
+        assert!(next.board() == &MultiBoard::from(next.pieces()));
+        assert!(next.state.prev() == Some(&pos.state));
+
+        let next = pos.play(Move::castle(Right::WhiteQueen));
+        assert_eq!(next.rights(), Rights::BLACK_KING);
+        assert_eq!(next.king_square(Color::White), Square::C1);
+        assert!(next.board() == &MultiBoard::from(next.pieces()));
+    }
+
+    #[test]
+    fn zobrist() {
+        let pos = Position::default();
+        assert_eq!(pos.state.key, pos.zobrist());
+
+        let pieces = PieceMap::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R").unwrap();
+        let kiwi = Position::new(pieces, Color::White, Rights::FULL, None).unwrap();
+
+        for pos in &[pos, kiwi] {
+            let mut moves = MoveVec::new();
+            pos.gen(&mut moves).legal();
+
+            for &mv in moves.iter() {
+                let next = pos.play(mv);
+                assert_eq!(next.state.key, next.zobrist(), "{:?}", mv);
+                assert_ne!(next.state.key, pos.state.key);
+
+                // Undoing the move restores the original key
+                assert_eq!(next.state.prev().unwrap().key, pos.state.key);
+
+                let mut replies = MoveVec::new();
+                next.gen(&mut replies).legal();
+                for &reply in replies.iter() {
+                    let last = next.play(reply);
+                    assert_eq!(last.state.key, last.zobrist(), "{:?} {:?}", mv, reply);
+                }
+            }
+        }
+
+        // Transpositions hash equally
+        let a = Position::default()
+            .play(Move::normal(Square::G1, Square::F3))
+            .play(Move::normal(Square::G8, Square::F6))
+            .play(Move::normal(Square::B1, Square::C3));
+        let b = Position::default()
+            .play(Move::normal(Square::B1, Square::C3))
+            .play(Move::normal(Square::G8, Square::F6))
+            .play(Move::normal(Square::G1, Square::F3));
+        assert_eq!(a.state.key, b.state.key);
+    }
+
+    #[test]
+    fn key() {
+        let play = |moves: &[(Square, Square)]| {
+            moves.iter().fold(Position::default(), |pos, &(src, dst)| {
+                pos.play(Move::normal(src, dst))
+            })
+        };
+
+        let a = play(&[(Square::E2, Square::E3), (Square::E7, Square::E6), (Square::D2, Square::D3)]);
+        let b = play(&[(Square::D2, Square::D3), (Square::E7, Square::E6), (Square::E2, Square::E3)]);
+        assert_eq!(a.key(), b.key());
+        assert_eq!(a.key(), a.zobrist());
+
+        // The en passant square left by the last double push tells these apart
+        let c = play(&[(Square::E2, Square::E4), (Square::G8, Square::F6), (Square::D2, Square::D4)]);
+        let d = play(&[(Square::D2, Square::D4), (Square::G8, Square::F6), (Square::E2, Square::E4)]);
+        assert!(c.key() != d.key());
+    }
+
+    #[test]
+    fn perft() {
+        let pos = Position::default();
+        assert_eq!(pos.perft(0), 1);
+        assert_eq!(pos.perft(3), 8902);
+
+        let kiwipete = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+        let kiwipete = Position::from_fen(kiwipete).unwrap();
+        assert_eq!(kiwipete.perft(1), 48);
+        assert_eq!(kiwipete.perft(2), 2039);
+
+        let divide = kiwipete.perft_divide(2);
+        assert_eq!(divide.len(), 48);
+        assert_eq!(divide.iter().map(|&(_, n)| n).sum::<u64>(), 2039);
+    }
+
+    #[test]
+    fn from_fen() {
+        use fen::{Fen, FenError};
+
+        let fen = "rnbqkbnr/pp1ppppp/8/2p5/4P3/8/PPPP1PPP/RNBQKBNR w KQkq c6 0 2";
+        let parsed: Fen = fen.parse().unwrap();
+        let pos = Position::new(parsed.pieces, parsed.color, parsed.castling, parsed.en_passant)
+            .unwrap();
+        assert_eq!(Position::from_fen(fen).unwrap().state.key, pos.state.key);
+
+        let errors = [
+            ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNX w KQkq - 0 1", FenError::BadPlacement),
+            ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR W KQkq - 0 1", FenError::BadActiveColor),
+            ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkx - 0 1", FenError::BadCastling),
+            ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq i3 0 1", FenError::BadEnPassant),
+            ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - x 1", FenError::BadHalfmove),
+            ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1.", FenError::BadFullmove),
+            ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq", FenError::WrongFieldCount),
+        ];
+        for &(fen, kind) in &errors {
+            assert_eq!(Position::from_fen(fen).err().unwrap().fen_kind(), Some(kind), "{}", fen);
+        }
+
+        let err = Position::from_fen("4k3/8/8/8/8/8/8/4K2R w Kk - 0 1").err().unwrap();
+        assert_eq!(err, FromFenError::Position(Error::Rights(Right::BlackKing)));
+        assert_eq!(err.fen_kind(), None);
+    }
+
+    #[test]
+    fn from_parts() {
+        let fen = "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2";
+        let expected = Position::from_fen(fen).ok().unwrap();
+
+        let pieces = expected.pieces().clone();
+        let pos = Position::from_parts(pieces.clone(), Color::White, Rights::FULL,
+                                       Some(Square::E6), 0, 2).ok().unwrap();
+        assert!(pos == expected);
+        assert!(pos.board() == expected.board());
+        assert_eq!(pos.state.key, expected.state.key);
+        assert_eq!(pos.fullmoves(), 2);
+
+        let err = |pieces: PieceMap, ep| {
+            Position::from_parts(pieces, Color::White, Rights::EMPTY, ep, 0, 1).err()
+        };
+
+        let mut kingless = pieces.clone();
+        kingless.remove(Square::E8);
+        assert_eq!(err(kingless, None), Some(Error::KingCount(Color::Black)));
+
+        let mut kingless = pieces.clone();
+        kingless.remove(Square::E1);
+        assert_eq!(err(kingless, None), Some(Error::KingCount(Color::White)));
+
+        // The en passant square must be behind a pawn that just moved
+        assert_eq!(err(pieces.clone(), Some(Square::E3)), Some(Error::EnPassant(Square::E3)));
+        assert_eq!(err(pieces.clone(), Some(Square::D6)), Some(Error::EnPassant(Square::D6)));
+    }
+
+    #[test]
+    fn to_fen() {
+        let fens = [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/pp1ppppp/8/2p5/4P3/8/PPPP1PPP/RNBQKBNR w KQkq c6 0 2",
+            "r3k2r/8/8/8/8/8/8/R3K2R b Kq - 12 40",
+        ];
+        for &fen in &fens {
+            let pos = Position::from_fen(fen).ok().unwrap();
+            assert_eq!(pos.to_fen().to_string(), fen);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde::de::value::{BorrowedStrDeserializer, Error};
+
+        let fens = [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/pp1ppppp/8/2p5/4P3/8/PPPP1PPP/RNBQKBNR w KQkq c6 0 2",
+        ];
+        for &fen in &fens {
+            let de  = BorrowedStrDeserializer::<Error>::new(fen);
+            let pos = Position::deserialize(de).ok().unwrap();
+            assert!(pos == Position::from_fen(fen).ok().unwrap());
+            assert!(pos.board() == &MultiBoard::from(pos.pieces()));
+        }
+
+        let de = BorrowedStrDeserializer::<Error>::new("8/8/8/8/8/8/8/8 w - - 0 1");
+        assert!(Position::deserialize(de).is_err());
+    }
+
+    #[test]
+    fn move_counters() {
+        let pos = Position::default();
+        assert_eq!((pos.halfmoves(), pos.fullmoves()), (0, 1));
+
+        // 1. Nf3 Nc6 2. Nc3 e5 3. Nxe5 Nxe5 4. Nb1
+        let moves = [
+            (Move::normal(Square::G1, Square::F3), 1, 1),
+            (Move::normal(Square::B8, Square::C6), 2, 2),
+            (Move::normal(Square::B1, Square::C3), 3, 2),
+            (Move::normal(Square::E7, Square::E5), 0, 3),
+            (Move::normal(Square::F3, Square::E5), 0, 3),
+            (Move::normal(Square::C6, Square::E5), 0, 4),
+            (Move::normal(Square::C3, Square::B1), 1, 4),
+        ];
+        let mut pos = pos;
+        for &(mv, halfmoves, fullmoves) in &moves {
+            pos = pos.play(mv);
+            assert_eq!(pos.halfmoves(), halfmoves, "{:?}", mv);
+            assert_eq!(pos.fullmoves(), fullmoves, "{:?}", mv);
+        }
+
+        let pos = Position::from_fen("4k3/8/8/8/8/8/8/4K3 b - - 7 42").ok().unwrap();
+        assert_eq!((pos.halfmoves(), pos.fullmoves()), (7, 42));
+
+        let pos = pos.play(Move::normal(Square::E8, Square::D8));
+        assert_eq!((pos.halfmoves(), pos.fullmoves()), (8, 43));
+    }
+
+    #[test]
+    fn game_over() {
+        let pos = Position::default();
+        assert!(!pos.is_check());
+        assert!(!pos.is_checkmate());
+        assert!(!pos.is_stalemate());
+
+        // Mate in one positions and their mating moves
+        let mates = [
+            ("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", Move::normal(Square::A1, Square::A8)),
+            ("rnbqkbnr/pppp1ppp/8/4p3/6P1/5P2/PPPPP2P/RNBQKBNR b KQkq - 0 2",
+             Move::normal(Square::D8, Square::H4)),
+            ("k7/8/1K6/8/8/8/8/6Q1 w - - 0 1", Move::normal(Square::G1, Square::G8)),
+        ];
+        for &(fen, mv) in &mates {
+            let pos = Position::from_fen(fen).unwrap();
+            assert!(!pos.is_checkmate());
+
+            let next = pos.play(mv);
+            assert!(next.is_check());
+            assert!(next.is_checkmate());
+            assert!(!next.is_stalemate());
+        }
+
+        let pos = Position::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+        assert!(!pos.is_check());
+        assert!(!pos.is_checkmate());
+        assert!(pos.is_stalemate());
+
+        // In check but able to escape
+        let pos = Position::from_fen("4k3/8/8/8/8/8/8/4RK2 b - - 0 1").unwrap();
+        assert!(pos.is_check());
+        assert!(!pos.is_checkmate());
+    }
+
+    #[test]
+    fn gives_check() {
+        use core::piece::Promotion;
+        use core::mv::kind;
+
+        let checks = [
+            // Direct
+            ("4k3/8/8/8/8/8/8/R3K3 w - - 0 1",
+             Move::normal(Square::A1, Square::A8), true),
+            ("4k3/8/8/8/8/8/8/R3K3 w - - 0 1",
+             Move::normal(Square::A1, Square::A7), false),
+            // Discovered
+            ("4k3/8/8/8/4B3/8/8/4R1K1 w - - 0 1",
+             Move::normal(Square::E4, Square::D5), true),
+            ("4k3/8/8/8/4B3/8/8/4R1K1 w - - 0 1",
+             Move::normal(Square::E1, Square::E2), false),
+            // Castling
+            ("5k2/8/8/8/8/8/8/4K2R w K - 0 1",
+             kind::Castle::new(Right::WhiteKing).into(), true),
+            ("4k3/8/8/8/8/8/8/4K2R w K - 0 1",
+             kind::Castle::new(Right::WhiteKing).into(), false),
+            // En passant, discovered by the captured pawn
+            ("6k1/8/8/3pP3/8/1B6/8/4K3 w - d6 0 1",
+             Move::en_passant(Square::E5, Square::D6).unwrap(), true),
+            // Promotion
+            ("3k4/6P1/8/8/8/8/8/4K3 w - - 0 1",
+             kind::Promotion::new(File::G, Color::White, Promotion::Queen).into(), true),
+            ("3k4/6P1/8/8/8/8/8/4K3 w - - 0 1",
+             kind::Promotion::new(File::G, Color::White, Promotion::Knight).into(), false),
+        ];
+
+        for &(fen, mv, check) in &checks {
+            let pos = Position::from_fen(fen).unwrap();
+            assert!(pos.legal_moves().contains(&mv), "{} {:?}", fen, mv);
+            assert_eq!(pos.gives_check(mv), check, "{} {:?}", fen, mv);
+            assert_eq!(pos.play(mv).is_check(), check, "{} {:?}", fen, mv);
+        }
+
+        // Every move agrees with actually playing it
+        for &(fen, _, _) in &checks {
+            let pos = Position::from_fen(fen).unwrap();
+            for &mv in pos.legal_moves().iter() {
+                assert_eq!(pos.gives_check(mv), pos.play(mv).is_check(), "{} {:?}", fen, mv);
+            }
+        }
+    }
+
+    #[test]
+    fn pins() {
+        let pos = Position::from_fen("4k3/8/8/b7/8/8/3N4/4K3 w - - 0 1").unwrap();
+        assert_eq!(pos.pinned(Color::White),  Square::D2.into());
+        assert_eq!(pos.pinners(Color::White), Square::A5.into());
+        assert!(pos.pinned(Color::Black).is_empty());
+        assert!(pos.legal_moves().iter().all(|mv| mv.src() != Square::D2));
+
+        // Two pieces between the king and slider are not pinned
+        let pos = Position::from_fen("4k3/8/8/b7/8/2P5/3N4/4K3 w - - 0 1").unwrap();
+        assert!(pos.pinned(Color::White).is_empty());
+        assert!(pos.pinners(Color::White).is_empty());
+
+        // Neither are pieces of the pinning color
+        let pos = Position::from_fen("4k3/4r3/8/8/8/8/4n3/4K3 w - - 0 1").unwrap();
+        assert!(pos.pinned(Color::White).is_empty());
+
+        // Pins on files and ranks
+        let pos = Position::from_fen("4k3/4r3/8/8/8/8/4R3/q2BK3 w - - 0 1").unwrap();
+        assert_eq!(pos.pinned(Color::White),  Square::E2 | Square::D1);
+        assert_eq!(pos.pinners(Color::White), Square::E7 | Square::A1);
+    }
+
+    #[test]
+    fn display() {
+        let pos = Position::default();
+        let s   = pos.to_string();
+        let mut lines = s.lines();
+
+        assert_eq!(lines.next(), Some("r n b q k b n r"));
+        assert_eq!(lines.next(), Some("p p p p p p p p"));
+        for _ in 0..4 {
+            assert_eq!(lines.next(), Some(". . . . . . . ."));
+        }
+        assert_eq!(lines.next(), Some("P P P P P P P P"));
+        assert_eq!(lines.next(), Some("R N B Q K B N R"));
+        assert_eq!(lines.next(), Some("White to move, castling: KQkq, en passant: -"));
+        assert_eq!(lines.next(), None);
+
+        let pos = pos.play(Move::normal(Square::E2, Square::E4));
+        assert!(pos.to_string().ends_with("Black to move, castling: KQkq, en passant: e3"));
+    }
+
+    #[test]
+    fn insufficient_material() {
+        let drawn = [
+            "8/8/4k3/8/8/4K3/8/8 w - - 0 1",
+            "8/8/4k3/8/8/3NK3/8/8 w - - 0 1",
+            "8/8/4k3/8/8/3BK3/8/8 b - - 0 1",
+            "8/3n4/4k3/8/8/4K3/8/8 w - - 0 1",
+            // Bishops on light squares
+            "8/8/4k1b1/8/8/3BK3/8/8 w - - 0 1",
+        ];
+        for fen in &drawn {
+            assert!(Position::from_fen(fen).unwrap().is_insufficient_material(), "{}", fen);
+        }
+
+        let not_drawn = [
+            "8/8/4k3/8/8/2NNK3/8/8 w - - 0 1",
+            // Bishops on opposite colors
+            "8/8/4kb2/8/8/3BK3/8/8 w - - 0 1",
+            // Both bishops belong to the same player
+            "8/8/4k3/8/8/2BBK3/8/8 w - - 0 1",
+            "8/8/4k3/8/8/3PK3/8/8 w - - 0 1",
+            "8/8/4k3/8/8/3RK3/8/8 w - - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        ];
+        for fen in &not_drawn {
+            assert!(!Position::from_fen(fen).unwrap().is_insufficient_material(), "{}", fen);
+        }
+    }
+
+    #[test]
+    fn draws() {
+        let shuffle = [
+            Move::normal(Square::G1, Square::F3),
+            Move::normal(Square::G8, Square::F6),
+            Move::normal(Square::F3, Square::G1),
+            Move::normal(Square::F6, Square::G8),
+        ];
+
+        let mut pos = Position::default();
+        for (i, &mv) in shuffle.iter().cycle().take(8).enumerate() {
+            assert!(!pos.is_repetition_draw(), "{}", i);
+            pos = pos.play(mv);
+        }
+        assert!(pos.is_repetition_draw());
+        assert!(!pos.is_fifty_move_draw());
+
+        // Only positions since the last pawn move are counted
+        let mut pos = pos.play(Move::normal(Square::E2, Square::E4));
+        assert_eq!(pos.state.halfmoves, 0);
+        assert!(!pos.is_repetition_draw());
+
+        let shuffle = [
+            Move::normal(Square::G8, Square::F6),
+            Move::normal(Square::G1, Square::F3),
+            Move::normal(Square::F6, Square::G8),
+            Move::normal(Square::F3, Square::G1),
+        ];
+        // The en passant square makes the position right after e4 distinct,
+        // so the first to occur three times is the one after ...Nf6
+        for (i, &mv) in shuffle.iter().cycle().take(9).enumerate() {
+            assert!(!pos.is_repetition_draw(), "{}", i);
+            pos = pos.play(mv);
+        }
+        assert_eq!(pos.state.halfmoves, 9);
+        assert!(pos.is_repetition_draw());
+
+        let mut pieces = PieceMap::new();
+        pieces.insert(Square::A1, Piece::WhiteKing);
+        pieces.insert(Square::H1, Piece::WhiteRook);
+        pieces.insert(Square::A8, Piece::BlackKing);
+        pieces.insert(Square::H8, Piece::BlackRook);
+
+        let mut pos = Position::new(pieces, Color::White, Rights::EMPTY, None).unwrap();
+        let files = [File::B, File::C, File::D, File::E, File::F, File::G];
+        for i in 0..100 {
+            assert!(!pos.is_fifty_move_draw(), "{}", i);
+
+            // Walk the rooks along their ranks to avoid repetitions
+            let rank = Rank::first(pos.player());
+            let src  = pos.pieces().squares(Piece::new(Role::Rook, pos.player()))
+                          .lsb().unwrap();
+            let dst  = Square::new(files[(i / 2) % files.len()], rank);
+            let dst  = if dst == src { Square::new(File::H, rank) } else { dst };
+            pos = pos.play(Move::normal(src, dst));
+        }
+        assert!(pos.is_fifty_move_draw());
+    }
+
+    #[test]
+    fn null_move() {
+        let pos = Position::default().play(Move::normal(Square::E2, Square::E4));
+        assert_eq!(pos.en_passant(), Some(Square::E3));
+
+        let mut null = pos.clone();
+        null.make_null();
+        assert_eq!(null.player(), Color::White);
+        assert_eq!(null.en_passant(), None);
+        assert_eq!(null.rights(), pos.rights());
+        assert_eq!(null.state.key, null.zobrist());
+        assert_ne!(null.state.key, pos.state.key);
+
+        // Moves can be played after a null move
+        let next = null.play(Move::normal(Square::D2, Square::D4));
+        assert_eq!(next.state.key, next.zobrist());
+
+        null.unmake_null();
+        assert!(null == pos);
+        assert_eq!(null.player(), Color::Black);
+        assert_eq!(null.en_passant(), Some(Square::E3));
+        assert_eq!(null.state.key, pos.state.key);
+    }
 }
@@ -1,7 +1,7 @@
 //! A move generator and options.
 
-use core::mv::kind::*;
-use core::mv::MoveVec;
+use core::mv::{kind, Matches, MoveVec};
+use prelude::*;
 use super::Position;
 
 /// A type that can be used to generate a series of moves.
@@ -13,11 +13,112 @@ pub struct MoveGen<'pos, 'buf> {
 impl<'a, 'b> MoveGen<'a, 'b> {
     /// Generates all legal moves.
     pub fn legal(&mut self) -> &mut Self {
-        self
+        let pos    = self.pos;
+        let player = pos.player();
+        let board  = pos.board();
+        let own    = pos.player_bits();
+        let opp    = pos.opponent_bits();
+        let all    = own | opp;
+        let empty  = !all;
+
+        let pawns = board.bits(Piece::new(Role::Pawn, player));
+        let start = match player {
+            Color::White => Rank::Two,
+            Color::Black => Rank::Seven,
+        };
+
+        for src in pawns {
+            let mut dsts = BitBoard::from(src).advance(player) & empty;
+            if !dsts.is_empty() && src.rank() == start {
+                dsts |= dsts.advance(player) & empty;
+            }
+            dsts |= src.pawn_attacks(player) & opp;
+
+            for dst in dsts {
+                if dst.rank() == Rank::last(player) {
+                    for piece in Promotion::ALL {
+                        if let Some(mv) = kind::Promotion::try_new(src, dst, piece) {
+                            self.push_legal(mv.into());
+                        }
+                    }
+                } else {
+                    self.push_legal(Move::normal(src, dst));
+                }
+            }
+
+            if let Some(ep) = pos.en_passant() {
+                if src.pawn_attacks(player).contains(ep) {
+                    if let Some(mv) = Move::en_passant(src, ep) {
+                        self.push_legal(mv);
+                    }
+                }
+            }
+        }
+
+        for src in own & !pawns {
+            if let Some(&piece) = pos.pieces().get(src) {
+                for dst in src.attacks(piece, all) & !own {
+                    self.push_legal(Move::normal(src, dst));
+                }
+            }
+        }
+
+        self.castle()
     }
 
-    /// Generates all pseudo-legal castling moves.
+    /// Generates all legal castling moves.
     pub fn castle(&mut self) -> &mut Self {
+        let pos    = self.pos;
+        let player = pos.player();
+        let board  = pos.board();
+        let king   = pos.king_square(player);
+
+        // Cannot castle out of check
+        if board.is_attacked(king, player) {
+            return self;
+        }
+
+        let all = board.all_bits();
+        for right in pos.rights() & Rights::from(player) {
+            if !all.path_is_empty(right) {
+                continue;
+            }
+
+            // Cannot castle through or into check
+            let mv   = Move::castle(right);
+            let path = BitBoard::between(mv.src(), mv.dst()) | mv.dst();
+            if path.into_iter().all(|sq| !board.is_attacked(sq, player)) {
+                self.buf.push(mv);
+            }
+        }
         self
     }
+
+    /// Pushes `mv` if it does not leave the player's king in check.
+    fn push_legal(&mut self, mv: Move) {
+        let pos    = self.pos;
+        let player = pos.player();
+        let pieces = pos.pieces();
+
+        let moved = match pieces.role_at(mv.src()) {
+            Some(role) => role,
+            None => return,
+        };
+        let captured = match mv.matches() {
+            Matches::EnPassant(_) => Some(Role::Pawn),
+            _ => pieces.role_at(mv.dst()),
+        };
+
+        let mut board = pos.board().clone();
+        board.apply(mv, player, moved, captured);
+
+        let king = match board.first(Piece::new(Role::King, player)) {
+            Some(sq) => sq,
+            None => return,
+        };
+
+        if !board.is_attacked(king, player) {
+            self.buf.push(mv);
+        }
+    }
 }
@@ -0,0 +1,391 @@
+//! Standard Algebraic Notation.
+
+use core::castle::Side;
+use core::mv::Matches;
+use super::*;
+
+/// The origin coordinates needed to tell a move apart from others in
+/// [Standard Algebraic Notation][san].
+///
+/// [san]: https://en.wikipedia.org/wiki/Algebraic_notation_(chess)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Disambiguation {
+    /// No other piece of the same role can move to the destination.
+    None,
+    /// The origin file is needed, as in `Rad1`.
+    File,
+    /// The origin rank is needed, as in `R1a3`.
+    Rank,
+    /// The whole origin square is needed, as in `Qa1b2`.
+    Both,
+}
+
+/// Returns the minimal disambiguation needed to write `mv` for `pos`.
+///
+/// Another piece of the same role that can legally move to the same square
+/// makes the move ambiguous. The file is preferred, followed by the rank, and
+/// then the whole square. Pawn moves and castling never need disambiguation,
+/// since pawn captures always name the origin file.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use hexe::position::san::{self, Disambiguation};
+/// use hexe::prelude::*;
+///
+/// let pos = Position::default();
+/// let mv  = Move::normal(Square::G1, Square::F3);
+///
+/// assert_eq!(san::disambiguation(&pos, mv), Disambiguation::None);
+/// ```
+pub fn disambiguation(pos: &Position, mv: Move) -> Disambiguation {
+    let pieces = pos.pieces();
+    let src    = mv.src();
+    let dst    = mv.dst();
+
+    let role = match pieces.role_at(src) {
+        Some(Role::Pawn) | None => return Disambiguation::None,
+        Some(role) => role,
+    };
+    if mv.kind() == mv::Kind::Castle {
+        return Disambiguation::None;
+    }
+
+    let moves = pos.legal_moves();
+
+    let mut ambiguous = false;
+    let mut same_file = false;
+    let mut same_rank = false;
+    for other in moves.iter().filter(|mv| mv.dst() == dst) {
+        let other = other.src();
+        if other == src || pieces.role_at(other) != Some(role) {
+            continue;
+        }
+        ambiguous  = true;
+        same_file |= other.file() == src.file();
+        same_rank |= other.rank() == src.rank();
+    }
+
+    if !ambiguous {
+        Disambiguation::None
+    } else if !same_file {
+        Disambiguation::File
+    } else if !same_rank {
+        Disambiguation::Rank
+    } else {
+        Disambiguation::Both
+    }
+}
+
+impl Position {
+    /// Returns the [Standard Algebraic Notation][san] of `mv`, which is
+    /// expected to be legal for `self`.
+    ///
+    /// The piece origin is disambiguated by file, rank, or both, only when
+    /// another piece of the same role can move to the same square. A `+` or
+    /// `#` is appended if the move gives check or checkmate.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe::prelude::*;
+    ///
+    /// let pos = Position::default();
+    ///
+    /// assert_eq!(pos.san(Move::normal(Square::G1, Square::F3)), "Nf3");
+    /// assert_eq!(pos.san(Move::normal(Square::E2, Square::E4)), "e4");
+    /// ```
+    ///
+    /// [san]: https://en.wikipedia.org/wiki/Algebraic_notation_(chess)
+    pub fn san(&self, mv: Move) -> String {
+        let mut san = String::with_capacity(8);
+        let pieces  = self.pieces();
+        let src     = mv.src();
+        let dst     = mv.dst();
+
+        if let Matches::Castle(mv) = mv.matches() {
+            san.push_str(match mv.right().side() {
+                Side::King  => "O-O",
+                Side::Queen => "O-O-O",
+            });
+        } else {
+            let role    = pieces.role_at(src).expect("no piece to move");
+            let capture = pieces.contains(dst) || mv.kind() == mv::Kind::EnPassant;
+
+            if role == Role::Pawn {
+                if capture {
+                    san.push(file_char(src.file()));
+                }
+            } else {
+                san.push(role.into());
+
+                match disambiguation(self, mv) {
+                    Disambiguation::None => {},
+                    Disambiguation::File => san.push(file_char(src.file())),
+                    Disambiguation::Rank => san.push(src.rank().into()),
+                    Disambiguation::Both => san.push_str(src.as_str_lower()),
+                }
+            }
+
+            if capture {
+                san.push('x');
+            }
+            san.push_str(dst.as_str_lower());
+
+            if let Matches::Promotion(mv) = mv.matches() {
+                san.push('=');
+                san.push(mv.piece().into());
+            }
+        }
+
+        let next = self.play(mv);
+        if next.is_checkmate() {
+            san.push('#');
+        } else if next.is_check() {
+            san.push('+');
+        }
+
+        san
+    }
+
+    /// Parses `s` as a move in [Standard Algebraic Notation][san], returning
+    /// the matching legal move for `self`.
+    ///
+    /// Check and annotation suffixes (`+`, `#`, `!`, `?`) as well as a trailing
+    /// `e.p.` are ignored. Castling may be written with either `O` or `0`.
+    /// `None` is returned if no legal move matches or if `s` is ambiguous.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe::prelude::*;
+    ///
+    /// let pos = Position::default();
+    ///
+    /// assert_eq!(pos.parse_san("Nf3"), Some(Move::normal(Square::G1, Square::F3)));
+    /// assert_eq!(pos.parse_san("e5"), None);
+    /// ```
+    ///
+    /// [san]: https://en.wikipedia.org/wiki/Algebraic_notation_(chess)
+    pub fn parse_san(&self, s: &str) -> Option<Move> {
+        let mut s = s.trim();
+        if s.ends_with("e.p.") {
+            s = s[..(s.len() - 4)].trim_right();
+        }
+        s = s.trim_right_matches(|ch| "+#!?".contains(ch));
+
+        let mut moves = MoveVec::new();
+        self.gen(&mut moves).legal();
+
+        let side = match s {
+            "O-O"   | "0-0"   => Some(Side::King),
+            "O-O-O" | "0-0-0" => Some(Side::Queen),
+            _ => None,
+        };
+        if let Some(side) = side {
+            let right = Right::new(self.player(), side);
+            return moves.iter().cloned().find(|&mv| {
+                mv.to_castle().map(|mv| mv.right()) == Some(right)
+            });
+        }
+
+        let (role, s) = match s.chars().next().and_then(Role::from_char) {
+            Some(role) if s.as_bytes()[0].is_ascii_uppercase() => (role, &s[1..]),
+            _ => (Role::Pawn, s),
+        };
+
+        let (s, promotion) = match s.find('=') {
+            Some(idx) => {
+                let mut chars = s[(idx + 1)..].chars();
+                let piece = chars.next()
+                                 .and_then(Role::from_char)
+                                 .and_then(Promotion::from_role)?;
+                if chars.next().is_some() {
+                    return None;
+                }
+                (&s[..idx], Some(piece))
+            },
+            None => (s, None),
+        };
+
+        if s.len() < 2 || !s.is_char_boundary(s.len() - 2) {
+            return None;
+        }
+        let (prefix, dst) = s.split_at(s.len() - 2);
+        let dst = Square::from_ascii(dst.as_bytes())?;
+        let prefix = prefix.trim_right_matches('x');
+
+        let mut file = None;
+        let mut rank = None;
+        for ch in prefix.chars() {
+            match ch {
+                'a'..='h' if file.is_none() => file = File::from_char(ch),
+                '1'..='8' if rank.is_none() => rank = Rank::from_char(ch),
+                _ => return None,
+            }
+        }
+
+        let pieces = self.pieces();
+        let mut found = None;
+        for &mv in moves.iter() {
+            let src = mv.src();
+            let matches = mv.dst() == dst
+                && mv.kind() != mv::Kind::Castle
+                && pieces.role_at(src) == Some(role)
+                && mv.matches().promotion().map(|mv| mv.piece()) == promotion
+                && (file.is_none() || file == Some(src.file()))
+                && (rank.is_none() || rank == Some(src.rank()));
+            if matches {
+                if found.is_some() {
+                    return None;
+                }
+                found = Some(mv);
+            }
+        }
+        found
+    }
+}
+
+#[inline]
+fn file_char(file: File) -> char {
+    char::from(file).to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disambiguation() {
+        let pos = Position::from_fen("6k1/8/8/R7/8/8/4K3/R6R w - - 0 1").unwrap();
+        assert_eq!(pos.san(Move::normal(Square::A1, Square::D1)), "Rad1");
+        assert_eq!(pos.san(Move::normal(Square::H1, Square::F1)), "Rhf1");
+        assert_eq!(pos.san(Move::normal(Square::A1, Square::A3)), "R1a3");
+        assert_eq!(pos.san(Move::normal(Square::A5, Square::B5)), "Rb5");
+
+        let pos = Position::from_fen("8/7k/8/8/8/Q7/8/Q1Q1K3 w - - 0 1").unwrap();
+        assert_eq!(pos.san(Move::normal(Square::A1, Square::B2)), "Qa1b2");
+        assert_eq!(pos.san(Move::normal(Square::C1, Square::B2)), "Qcb2");
+    }
+
+    #[test]
+    fn disambiguation_kinds() {
+        let pos = Position::from_fen("6k1/8/8/R7/8/8/4K3/R6R w - - 0 1").unwrap();
+        let cases = [
+            (Move::normal(Square::A1, Square::D1), Disambiguation::File),
+            (Move::normal(Square::A5, Square::A3), Disambiguation::Rank),
+            (Move::normal(Square::A5, Square::B5), Disambiguation::None),
+            (Move::normal(Square::E2, Square::E3), Disambiguation::None),
+        ];
+        for &(mv, exp) in &cases {
+            assert_eq!(super::disambiguation(&pos, mv), exp);
+        }
+
+        // Three queens attacking one square
+        let pos = Position::from_fen("8/7k/8/8/8/Q7/8/Q1Q1K3 w - - 0 1").unwrap();
+        let cases = [
+            (Move::normal(Square::A1, Square::B2), Disambiguation::Both),
+            (Move::normal(Square::A3, Square::B2), Disambiguation::Rank),
+            (Move::normal(Square::C1, Square::B2), Disambiguation::File),
+        ];
+        for &(mv, exp) in &cases {
+            assert_eq!(super::disambiguation(&pos, mv), exp);
+        }
+    }
+
+    #[test]
+    fn captures() {
+        let pos = Position::from_fen("4k3/8/8/3p4/4P3/8/8/4K1N1 w - - 0 1").unwrap();
+        assert_eq!(pos.san(Move::normal(Square::E4, Square::D5)), "exd5");
+        assert_eq!(pos.san(Move::normal(Square::E4, Square::E5)), "e5");
+
+        let pos = Position::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1").unwrap();
+        let mv  = Move::en_passant(Square::E5, Square::D6).unwrap();
+        assert_eq!(pos.san(mv), "exd6");
+    }
+
+    #[test]
+    fn castling() {
+        let pos = Position::from_fen("4k3/8/8/8/8/8/8/R3K2R w KQ - 0 1").unwrap();
+        assert_eq!(pos.san(Move::castle(Right::WhiteKing)), "O-O");
+        assert_eq!(pos.san(Move::castle(Right::WhiteQueen)), "O-O-O");
+    }
+
+    #[test]
+    fn promotion_and_check() {
+        let pos = Position::from_fen("7k/4P3/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let queen  = mv::kind::Promotion::new(File::E, Color::White, Promotion::Queen);
+        let knight = mv::kind::Promotion::new(File::E, Color::White, Promotion::Knight);
+        assert_eq!(pos.san(queen.into()), "e8=Q+");
+        assert_eq!(pos.san(knight.into()), "e8=N");
+    }
+
+    #[test]
+    fn checkmate() {
+        let pos = Position::default()
+            .play(Move::normal(Square::F2, Square::F3))
+            .play(Move::normal(Square::E7, Square::E5))
+            .play(Move::normal(Square::G2, Square::G4));
+        assert_eq!(pos.san(Move::normal(Square::D8, Square::H4)), "Qh4#");
+    }
+
+    #[test]
+    fn parse() {
+        let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+        let pos = Position::from_fen(fen).unwrap();
+        assert_eq!(pos.parse_san("O-O-O"), Some(Move::castle(Right::WhiteQueen)));
+        assert_eq!(pos.parse_san("0-0"), Some(Move::castle(Right::WhiteKing)));
+        assert_eq!(pos.parse_san("Nb5"), Some(Move::normal(Square::C3, Square::B5)));
+        assert_eq!(pos.parse_san("Ng4"), Some(Move::normal(Square::E5, Square::G4)));
+        assert_eq!(pos.parse_san("Nd4"), None);
+        assert_eq!(pos.parse_san("Qxf6!?"), Some(Move::normal(Square::F3, Square::F6)));
+
+        let pos = Position::from_fen("1n2k3/P7/8/3pP3/8/8/8/RN2K3 w - d6 0 1").unwrap();
+        let mv = Move::en_passant(Square::E5, Square::D6).unwrap();
+        assert_eq!(pos.parse_san("exd6 e.p."), Some(mv));
+        assert_eq!(pos.parse_san("Nd2"), Some(Move::normal(Square::B1, Square::D2)));
+
+        let mv = mv::kind::Promotion::try_new(Square::A7, Square::B8, Promotion::Knight).unwrap();
+        assert_eq!(pos.parse_san("axb8=N"), Some(mv.into()));
+        assert_eq!(pos.parse_san("a8=N+"), Some(mv::kind::Promotion::new(File::A, Color::White, Promotion::Knight).into()));
+        assert_eq!(pos.parse_san("a8"), None);
+    }
+
+    #[test]
+    fn parse_ambiguous() {
+        let pos = Position::from_fen("6k1/8/8/R7/8/8/4K3/R6R w - - 0 1").unwrap();
+        assert_eq!(pos.parse_san("Rd1"), None);
+        assert_eq!(pos.parse_san("Rad1"), Some(Move::normal(Square::A1, Square::D1)));
+        assert_eq!(pos.parse_san("Ra1d1"), Some(Move::normal(Square::A1, Square::D1)));
+        assert_eq!(pos.parse_san("Ra3"), None);
+        assert_eq!(pos.parse_san("R5a3"), Some(Move::normal(Square::A5, Square::A3)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let fens = [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq - 0 1",
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+            "6k1/8/8/R7/8/8/4K3/R6R w - - 0 1",
+            "8/7k/8/8/8/Q7/8/Q1Q1K3 w - - 0 1",
+        ];
+
+        for &fen in &fens {
+            let pos = Position::from_fen(fen).unwrap();
+            let mut moves = MoveVec::new();
+            pos.gen(&mut moves).legal();
+
+            for &mv in moves.iter() {
+                let san = pos.san(mv);
+                assert_eq!(pos.parse_san(&san), Some(mv), "{}", san);
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Static exchange evaluation.
+
+use std::cmp;
+
+use core::mv::Matches;
+use super::*;
+
+/// The maximum number of captures in an exchange on a single square.
+const MAX_EXCHANGES: usize = 32;
+
+impl Position {
+    /// Returns the [static exchange evaluation][see] of `mv` in centipawns
+    /// from the perspective of the player making the move.
+    ///
+    /// The exchange is resolved by repeatedly capturing on the destination
+    /// square with the least valuable attacker, taking into account sliding
+    /// pieces revealed behind captured pieces. Pins are not considered.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe::prelude::*;
+    ///
+    /// let pos = Position::default();
+    /// let mv  = Move::normal(Square::E2, Square::E4);
+    ///
+    /// assert_eq!(pos.see(mv), 0);
+    /// ```
+    ///
+    /// [see]: https://www.chessprogramming.org/Static_Exchange_Evaluation
+    pub fn see(&self, mv: Move) -> i32 {
+        let board = self.board();
+        let src   = mv.src();
+        let dst   = mv.dst();
+
+        let mover = match self.pieces().role_at(src) {
+            Some(role) => role,
+            None => return 0,
+        };
+
+        let mut occupied = board.all_bits() ^ src;
+        let mut gain = [0i32; MAX_EXCHANGES];
+
+        // The value of the piece currently sitting on `dst`
+        let mut last = mover.value() as i32;
+
+        match mv.matches() {
+            Matches::Castle(_) => return 0,
+            Matches::EnPassant(mv) => {
+                occupied ^= mv.capture();
+                gain[0] = Role::Pawn.value() as i32;
+            },
+            Matches::Promotion(mv) => {
+                let promotion = Role::from(mv.piece()).value() as i32;
+                let captured  = self.pieces().role_at(dst)
+                                    .map_or(0, |role| role.value() as i32);
+                gain[0] = captured + promotion - last;
+                last    = promotion;
+            },
+            Matches::Normal(_) => {
+                gain[0] = self.pieces().role_at(dst)
+                              .map_or(0, |role| role.value() as i32);
+            },
+        }
+
+        let mut attackers = board.attackers_to(dst, occupied);
+        let mut color = self.player();
+        let mut depth = 0;
+
+        while depth + 1 < MAX_EXCHANGES {
+            color = !color;
+
+            let own = attackers & board.bits(color);
+            let next = Role::ALL.filter_map(|role| {
+                (own & board.bits(role)).lsb().map(|sq| (role, sq))
+            }).next();
+
+            let (role, sq) = match next {
+                Some(next) => next,
+                None => break,
+            };
+
+            // The king can't capture into a defended square
+            if role == Role::King && attackers.intersects(board.bits(!color)) {
+                break;
+            }
+
+            depth += 1;
+            gain[depth] = last - gain[depth - 1];
+            last = role.value() as i32;
+
+            occupied ^= sq;
+            attackers = board.attackers_to(dst, occupied);
+        }
+
+        while depth > 0 {
+            gain[depth - 1] = -cmp::max(-gain[depth - 1], gain[depth]);
+            depth -= 1;
+        }
+
+        gain[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pawn_defended_by_pawn() {
+        let pos = Position::from_fen("4k3/8/2p5/3p4/4P3/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(pos.see(Move::normal(Square::E4, Square::D5)), 0);
+    }
+
+    #[test]
+    fn hanging_piece() {
+        let pos = Position::from_fen("4k3/8/8/3n4/8/8/8/3RK3 w - - 0 1").unwrap();
+        assert_eq!(pos.see(Move::normal(Square::D1, Square::D5)), 320);
+    }
+
+    #[test]
+    fn losing_capture() {
+        let pos = Position::from_fen("4k3/8/2p5/3p4/8/8/8/3QK3 w - - 0 1").unwrap();
+        assert_eq!(pos.see(Move::normal(Square::D1, Square::D5)), -800);
+    }
+
+    #[test]
+    fn x_ray() {
+        // Doubled rooks win the pawn against a single defender
+        let pos = Position::from_fen("3rk3/8/8/3p4/8/8/3R4/3RK3 w - - 0 1").unwrap();
+        assert_eq!(pos.see(Move::normal(Square::D2, Square::D5)), 100);
+
+        // Black's doubled rooks make the exchange a losing one
+        let pos = Position::from_fen("3rk3/3r4/8/3p4/8/8/3R4/3RK3 w - - 0 1").unwrap();
+        assert_eq!(pos.see(Move::normal(Square::D2, Square::D5)), 100 - 500);
+    }
+
+    #[test]
+    fn en_passant() {
+        let pos = Position::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1").unwrap();
+        let mv = Move::en_passant(Square::E5, Square::D6).unwrap();
+        assert_eq!(pos.see(mv), 100);
+    }
+}
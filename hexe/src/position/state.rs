@@ -16,6 +16,15 @@ pub struct State {
 
     /// The castle rights for both players.
     pub(super) rights: Rights,
+
+    /// The Zobrist hash of the position.
+    pub(super) key: u64,
+
+    /// The number of halfmoves since the last capture or pawn move.
+    pub(super) halfmoves: u32,
+
+    /// The fullmove number, starting at 1 and incremented after Black moves.
+    pub(super) fullmoves: u32,
 }
 
 impl PartialEq for State {
@@ -59,15 +68,23 @@ impl fmt::Debug for State {
             .field("prev",       &self.prev())
             .field("en_passant", &self.en_passant())
             .field("rights",     &self.rights())
+            .field("halfmoves",  &self.halfmoves())
+            .field("fullmoves",  &self.fullmoves())
             .finish()
     }
 }
 
+/// The Zobrist hash of `Position::STANDARD`, verified in tests.
+pub(super) const STANDARD_KEY: u64 = 0xC3C9_112B_17D1_B1C4;
+
 impl State {
     pub(crate) const STANDARD: State = State {
         prev: None,
         en_passant: None,
         rights: Rights::FULL,
+        key: STANDARD_KEY,
+        halfmoves: 0,
+        fullmoves: 1,
     };
 
     /// Returns the previous state.
@@ -87,4 +104,16 @@ impl State {
     pub fn rights(&self) -> Rights {
         self.rights
     }
+
+    /// Returns the number of halfmoves since the last capture or pawn move.
+    #[inline]
+    pub fn halfmoves(&self) -> u32 {
+        self.halfmoves
+    }
+
+    /// Returns the fullmove number.
+    #[inline]
+    pub fn fullmoves(&self) -> u32 {
+        self.fullmoves
+    }
 }
@@ -3,6 +3,7 @@ use std::mem;
 
 use uncon::*;
 
+use core::mv::Move;
 use zero::{Zero, ZeroBuffer};
 
 #[cfg(all(test, nightly))]
@@ -86,6 +87,48 @@ impl Table {
         Cluster::slice_mut(&mut self.0)
     }
 
+    /// Returns the entry stored for `key`, if any.
+    pub fn probe(&self, key: u64) -> Option<&Entry> {
+        let frag = Entry::fragment(key);
+        let cluster = self.clusters().get(self.index(key))?;
+        cluster.entries().iter().find(|e| e.bound != 0 && e.key == frag)
+    }
+
+    /// Stores the entry for `key` within its cluster.
+    ///
+    /// An existing entry for `key` is always replaced with the newer one.
+    /// Otherwise, an empty entry is used if available, or else the entry with
+    /// the shallowest depth is replaced.
+    pub fn store(&mut self, key: u64, mv: Move, val: i16, depth: u8, bound: Bound) {
+        if self.clusters().is_empty() {
+            return;
+        }
+        let frag    = Entry::fragment(key);
+        let index   = self.index(key);
+        let entries = self.clusters_mut()[index].entries_mut();
+
+        let slot = match entries.iter().position(|e| e.bound == 0 || e.key == frag) {
+            Some(slot) => slot,
+            None => {
+                let mut slot = 0;
+                for (i, entry) in entries.iter().enumerate().skip(1) {
+                    if entry.depth < entries[slot].depth {
+                        slot = i;
+                    }
+                }
+                slot
+            },
+        };
+
+        entries[slot] = Entry { key: frag, mv, val, depth, bound: bound as u8 };
+    }
+
+    /// Returns the cluster index for `key`.
+    fn index(&self, key: u64) -> usize {
+        // The number of clusters is always a power of two
+        (key as usize) & (self.clusters().len().wrapping_sub(1))
+    }
+
     /// Zeroes out the entire table.
     pub fn clear(&mut self) {
         self.clusters_mut().zero();
@@ -121,11 +164,65 @@ impl Cluster {
     }
 }
 
+/// A table entry for a position.
 #[derive(Debug, Copy, Clone)]
 #[repr(C)]
-struct Entry {
-    mv:  u16,
-    val: i16,
+pub struct Entry {
+    key:   u16,
+    mv:    Move,
+    val:   i16,
+    depth: u8,
+    /// The `Bound` of `val`, or 0 if the entry is unused.
+    bound: u8,
 }
 
 unsafe impl Zero for Entry {}
+
+impl Entry {
+    /// Returns the bits of `key` used to identify an entry within a cluster.
+    #[inline]
+    fn fragment(key: u64) -> u16 {
+        (key >> 48) as u16
+    }
+
+    /// Returns the best move found for the position.
+    #[inline]
+    pub fn mv(&self) -> Move {
+        self.mv
+    }
+
+    /// Returns the value found for the position.
+    #[inline]
+    pub fn val(&self) -> i16 {
+        self.val
+    }
+
+    /// Returns the depth searched for the position.
+    #[inline]
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the kind of bound the value places on the position's score.
+    #[inline]
+    pub fn bound(&self) -> Bound {
+        match self.bound {
+            1 => Bound::Exact,
+            2 => Bound::Lower,
+            _ => Bound::Upper,
+        }
+    }
+}
+
+/// The kind of bound an [`Entry`](struct.Entry.html) value places on the score
+/// of its position.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Bound {
+    /// The value is the exact score.
+    Exact = 1,
+    /// The score is at least the value.
+    Lower,
+    /// The score is at most the value.
+    Upper,
+}
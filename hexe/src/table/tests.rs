@@ -1,4 +1,5 @@
 use super::*;
+use core::prelude::*;
 
 #[test]
 fn new_zero() {
@@ -8,7 +9,7 @@ fn new_zero() {
         let table = Table::new(n);
         for cls in table.clusters() {
             for ent in cls.entries().iter() {
-                s += ent.mv;
+                s += u16::from(ent.mv());
             }
         }
     }
@@ -38,3 +39,58 @@ fn is_aligned() {
         assert!(table.0.is_aligned());
     }
 }
+
+#[test]
+fn probe_store() {
+    let mut table = Table::new(1);
+    let key = 0xDEAD_BEEF_0123_4567;
+    let mv  = Move::normal(Square::E2, Square::E4);
+
+    assert!(table.probe(key).is_none());
+
+    table.store(key, mv, -25, 4, Bound::Upper);
+    let entry = table.probe(key).unwrap();
+    assert_eq!(entry.mv(), mv);
+    assert_eq!(entry.val(), -25);
+    assert_eq!(entry.depth(), 4);
+    assert_eq!(entry.bound(), Bound::Upper);
+
+    // Newer entries for the same key always replace older ones
+    table.store(key, mv, 10, 2, Bound::Exact);
+    let entry = table.probe(key).unwrap();
+    assert_eq!(entry.val(), 10);
+    assert_eq!(entry.bound(), Bound::Exact);
+
+    table.clear();
+    assert!(table.probe(key).is_none());
+}
+
+#[test]
+fn replacement() {
+    let mut table = Table::new(1);
+    let mv = Move::normal(Square::G1, Square::F3);
+
+    // Keys sharing the same cluster but with different fragments
+    let key = |i: u64| (i << 48) | 42;
+    let depths = [9, 4, 7, 8, 2, 6, 5, 3];
+    assert_eq!(depths.len(), ENTRY_COUNT);
+
+    for (i, &depth) in depths.iter().enumerate() {
+        table.store(key(i as u64 + 1), mv, 0, depth, Bound::Lower);
+    }
+    for i in 0..depths.len() {
+        assert!(table.probe(key(i as u64 + 1)).is_some());
+    }
+
+    // The shallowest entry is replaced
+    table.store(key(100), mv, 0, 1, Bound::Lower);
+    assert!(table.probe(key(100)).is_some());
+    assert!(table.probe(key(5)).is_none());
+
+    // Now the new entry is the shallowest
+    table.store(key(101), mv, 0, 10, Bound::Lower);
+    assert!(table.probe(key(100)).is_none());
+    for i in (0..depths.len()).filter(|&i| i != 4) {
+        assert!(table.probe(key(i as u64 + 1)).is_some());
+    }
+}
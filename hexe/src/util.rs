@@ -1,5 +1,6 @@
 use std::mem;
 use std::ptr;
+use std::time::Duration;
 
 const LOWER_BIT: u8 = 32;
 
@@ -19,6 +20,12 @@ impl<T> AnySend<T> {
     pub unsafe fn get(self) -> T { self.0 }
 }
 
+/// Returns the total number of whole milliseconds in `duration`.
+#[inline]
+pub fn millis(duration: Duration) -> u64 {
+    duration.as_secs() * 1000 + u64::from(duration.subsec_millis())
+}
+
 #[inline]
 pub unsafe fn zero<T: ?Sized>(val: &mut T) {
     let len = mem::size_of_val(val);
@@ -4,13 +4,13 @@ use std::{fmt, hash, mem, ptr};
 
 use core::castle::Rights;
 use core::misc::Extract;
-use core::piece::Role;
+use core::piece::Piece;
 use core::square::{File, Square};
 use zero::Zero;
 
 mod tables;
 
-const NUM_PIECES:  usize = 6;
+const NUM_PIECES:  usize = 12;
 const NUM_SQUARES: usize = 64;
 const PIECE_TOTAL: usize = NUM_SQUARES * NUM_PIECES;
 const NUM_CASTLE:  usize = 0b1111 + 1;
@@ -61,9 +61,11 @@ impl Clone for Zobrist {
 impl fmt::Debug for Zobrist {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // `[u64; 64]` does not implement `fmt::Debug`
-        let pieces: [&[u64]; 6] = [
-            &self.pieces[0], &self.pieces[1], &self.pieces[2],
-            &self.pieces[3], &self.pieces[4], &self.pieces[5],
+        let pieces: [&[u64]; NUM_PIECES] = [
+            &self.pieces[0],  &self.pieces[1],  &self.pieces[2],
+            &self.pieces[3],  &self.pieces[4],  &self.pieces[5],
+            &self.pieces[6],  &self.pieces[7],  &self.pieces[8],
+            &self.pieces[9],  &self.pieces[10], &self.pieces[11],
         ];
         f.debug_struct("Zobrist")
             .field("pieces",     &pieces)
@@ -128,10 +130,10 @@ impl ::rand::Rand for Zobrist {
 }
 
 impl Zobrist {
-    /// Returns the key for the piece role at a square.
+    /// Returns the key for the piece at a square.
     #[inline]
-    pub fn piece(&self, role: Role, square: Square) -> u64 {
-        self.pieces[role as usize][square as usize]
+    pub fn piece(&self, piece: Piece, square: Square) -> u64 {
+        self.pieces[piece as usize][square as usize]
     }
 
     /// Returns the key for the castle rights.
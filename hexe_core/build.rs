@@ -1,7 +1,161 @@
 extern crate version_check;
 
+use std::env;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
 fn main() {
     if let Some(true) = version_check::supports_features() {
         println!("cargo:rustc-cfg=nightly");
     }
+
+    println!("cargo:rustc-check-cfg=cfg(const_fn)");
+    if let Some((true, _)) = version_check::is_min_version("1.31.0") {
+        println!("cargo:rustc-cfg=const_fn");
+    }
+
+    println!("cargo:rustc-check-cfg=cfg(try_from)");
+    if let Some((true, _)) = version_check::is_min_version("1.34.0") {
+        println!("cargo:rustc-cfg=try_from");
+    }
+
+    let out = env::var_os("OUT_DIR").unwrap();
+    let out = Path::new(&out);
+
+    steps::write_tables(out).unwrap();
+
+    if env::var_os("CARGO_FEATURE_BMI2").is_some() {
+        pext::write_tables(&out.join("pext.rs")).unwrap();
+    }
+}
+
+/// Generates the knight and king attack tables.
+mod steps {
+    use super::*;
+
+    include!("src/square/offsets.rs");
+
+    fn attacks(sq: i8, offsets: &[(i8, i8)]) -> u64 {
+        let mut attacks = 0;
+        for &(df, dr) in offsets {
+            let (f, r) = (sq % 8 + df, sq / 8 + dr);
+            if (f | r) & !7 == 0 {
+                attacks |= 1 << (r * 8 + f);
+            }
+        }
+        attacks
+    }
+
+    fn write_table(path: &Path, offsets: &[(i8, i8)]) -> io::Result<()> {
+        let mut w = BufWriter::new(File::create(path)?);
+        writeln!(w, "[")?;
+        for sq in 0..64 {
+            writeln!(w, "    0x{:016X},", attacks(sq, offsets))?;
+        }
+        writeln!(w, "]")
+    }
+
+    pub fn write_tables(out: &Path) -> io::Result<()> {
+        write_table(&out.join("knight.rs"), &KNIGHT_OFFSETS)?;
+        write_table(&out.join("king.rs"), &KING_OFFSETS)
+    }
+}
+
+/// Generates the attack tables indexed via the `PEXT` instruction.
+mod pext {
+    use super::*;
+
+    const ROOK:   [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+    const BISHOP: [(i32, i32); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+    fn in_bounds(file: i32, rank: i32) -> bool {
+        (file | rank) & !7 == 0
+    }
+
+    /// The squares whose occupancy affects attacks from `sq`, excluding the
+    /// final square of each ray.
+    fn mask(sq: i32, dirs: &[(i32, i32)]) -> u64 {
+        let mut mask = 0;
+        for &(df, dr) in dirs {
+            let (mut f, mut r) = (sq % 8 + df, sq / 8 + dr);
+            while in_bounds(f + df, r + dr) {
+                mask |= 1 << (r * 8 + f);
+                f += df;
+                r += dr;
+            }
+        }
+        mask
+    }
+
+    fn attacks(sq: i32, occupied: u64, dirs: &[(i32, i32)]) -> u64 {
+        let mut attacks = 0;
+        for &(df, dr) in dirs {
+            let (mut f, mut r) = (sq % 8 + df, sq / 8 + dr);
+            while in_bounds(f, r) {
+                let bit = 1 << (r * 8 + f);
+                attacks |= bit;
+                if occupied & bit != 0 {
+                    break;
+                }
+                f += df;
+                r += dr;
+            }
+        }
+        attacks
+    }
+
+    /// Scatters the low bits of `index` into the set bits of `mask`; the
+    /// inverse of `PEXT`.
+    fn deposit(index: usize, mut mask: u64) -> u64 {
+        let mut result = 0;
+        let mut bit = 0;
+        while mask != 0 {
+            let lsb = mask & mask.wrapping_neg();
+            if index & (1 << bit) != 0 {
+                result |= lsb;
+            }
+            mask ^= lsb;
+            bit += 1;
+        }
+        result
+    }
+
+    fn write_table<W: Write>(w: &mut W, name: &str, dirs: &[(i32, i32)]) -> io::Result<()> {
+        let masks: Vec<u64> = (0..64).map(|sq| mask(sq, dirs)).collect();
+
+        let mut offsets = Vec::with_capacity(64);
+        let mut table = Vec::new();
+        for sq in 0..64 {
+            offsets.push(table.len());
+            let mask = masks[sq as usize];
+            for index in 0..(1usize << mask.count_ones()) {
+                table.push(attacks(sq, deposit(index, mask), dirs));
+            }
+        }
+
+        writeln!(w, "static {}_MASKS: [u64; 64] = [", name)?;
+        for mask in &masks {
+            writeln!(w, "    0x{:016X},", mask)?;
+        }
+        writeln!(w, "];\n")?;
+
+        writeln!(w, "static {}_OFFSETS: [usize; 64] = [", name)?;
+        for offset in &offsets {
+            writeln!(w, "    {},", offset)?;
+        }
+        writeln!(w, "];\n")?;
+
+        writeln!(w, "static {}_ATTACKS: [u64; {}] = [", name, table.len())?;
+        for attacks in &table {
+            writeln!(w, "    0x{:016X},", attacks)?;
+        }
+        writeln!(w, "];\n")
+    }
+
+    pub fn write_tables(path: &Path) -> io::Result<()> {
+        let mut w = BufWriter::new(File::create(path)?);
+        write_table(&mut w, "ROOK", &ROOK)?;
+        write_table(&mut w, "BISHOP", &BISHOP)
+    }
 }
@@ -67,6 +67,8 @@ pub use self::subsets::*;
 
 #[cfg(all(test, nightly))]
 mod benches;
+#[cfg(test)]
+mod tests;
 
 impl_rand!(u64 => BitBoard);
 
@@ -265,6 +267,98 @@ impl BitBoard {
     /// Black board squares.
     pub const BLACK: BitBoard = BitBoard(0xAA55AA55AA55AA55);
 
+    /// File A squares.
+    pub const FILE_A: BitBoard = masks::FILE_A;
+
+    /// File B squares.
+    pub const FILE_B: BitBoard = masks::FILE_B;
+
+    /// File C squares.
+    pub const FILE_C: BitBoard = masks::FILE_C;
+
+    /// File D squares.
+    pub const FILE_D: BitBoard = masks::FILE_D;
+
+    /// File E squares.
+    pub const FILE_E: BitBoard = masks::FILE_E;
+
+    /// File F squares.
+    pub const FILE_F: BitBoard = masks::FILE_F;
+
+    /// File G squares.
+    pub const FILE_G: BitBoard = masks::FILE_G;
+
+    /// File H squares.
+    pub const FILE_H: BitBoard = masks::FILE_H;
+
+    /// Rank 1 squares.
+    pub const RANK_1: BitBoard = masks::RANK_1;
+
+    /// Rank 2 squares.
+    pub const RANK_2: BitBoard = masks::RANK_2;
+
+    /// Rank 3 squares.
+    pub const RANK_3: BitBoard = masks::RANK_3;
+
+    /// Rank 4 squares.
+    pub const RANK_4: BitBoard = masks::RANK_4;
+
+    /// Rank 5 squares.
+    pub const RANK_5: BitBoard = masks::RANK_5;
+
+    /// Rank 6 squares.
+    pub const RANK_6: BitBoard = masks::RANK_6;
+
+    /// Rank 7 squares.
+    pub const RANK_7: BitBoard = masks::RANK_7;
+
+    /// Rank 8 squares.
+    pub const RANK_8: BitBoard = masks::RANK_8;
+
+    /// Squares on the outer edges of the board.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let sides = BitBoard::FILE_A | BitBoard::FILE_H;
+    ///
+    /// assert!(BitBoard::EDGES.contains(sides));
+    /// for file in File::range(File::B, File::G) {
+    ///     assert!(!sides.intersects(file));
+    /// }
+    /// ```
+    pub const EDGES: BitBoard = BitBoard(
+        masks::FILE_A.0 | masks::FILE_H.0 | masks::RANK_1.0 | masks::RANK_8.0
+    );
+
+    /// The four center squares: D4, E4, D5, and E5.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let center = Square::D4 | Square::E4 | Square::D5 | Square::E5;
+    /// assert_eq!(BitBoard::CENTER, center);
+    /// ```
+    pub const CENTER: BitBoard = BitBoard(0x0000001818000000);
+
+    /// Light squares. This is the same as [`WHITE`](#associatedconstant.WHITE).
+    pub const LIGHT_SQUARES: BitBoard = Self::WHITE;
+
+    /// Dark squares. This is the same as [`BLACK`](#associatedconstant.BLACK).
+    pub const DARK_SQUARES: BitBoard = Self::BLACK;
+
+    /// King side squares, on files E through H.
+    pub const KING_SIDE: BitBoard = BitBoard(0xF0F0F0F0F0F0F0F0);
+
+    /// Queen side squares, on files A through D.
+    pub const QUEEN_SIDE: BitBoard = BitBoard(!Self::KING_SIDE.0);
+
     /// Generates a random `BitBoard` with few bits set.
     #[inline]
     #[cfg(any(test, feature = "rand"))]
@@ -285,6 +379,261 @@ impl BitBoard {
         start.line(end)
     }
 
+    /// Returns whether `self` has more than one bit set, the same as
+    /// [`has_multiple`](#method.has_multiple).
+    ///
+    /// This is faster than comparing [`len`](#method.len) against 1, which
+    /// makes it useful for detecting double check.
+    ///
+    /// # Examples
+    ///
+    /// The empty board does not have more than one bit:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert!(!BitBoard::EMPTY.more_than_one());
+    /// assert!(!BitBoard::from(Square::A1).more_than_one());
+    /// assert!((Square::A1 | Square::H8).more_than_one());
+    /// assert!(BitBoard::FULL.more_than_one());
+    /// ```
+    #[inline]
+    pub fn more_than_one(self) -> bool {
+        self.has_multiple()
+    }
+
+    /// Returns whether every bit of `self` is also set in `other`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let board = Square::A2 | Square::B2;
+    ///
+    /// assert!(board.is_subset(Rank::Two));
+    /// assert!(!board.is_subset(Square::A2));
+    /// assert!(BitBoard::EMPTY.is_subset(Square::A2));
+    /// ```
+    #[inline]
+    pub fn is_subset<T: Into<BitBoard>>(self, other: T) -> bool {
+        self & other == self
+    }
+
+    /// Returns whether every bit of `other` is also set in `self`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let board = BitBoard::from(Rank::Two);
+    ///
+    /// assert!(board.is_superset(Square::A2 | Square::B2));
+    /// assert!(!board.is_superset(Square::A2 | Square::A3));
+    /// ```
+    #[inline]
+    pub fn is_superset<T: Into<BitBoard>>(self, other: T) -> bool {
+        other.into().is_subset(self)
+    }
+
+    /// Returns whether `self` and `other` have no bits in common.
+    ///
+    /// This is the opposite of [`intersects`](#method.intersects).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert!(BitBoard::from(Square::A1).is_disjoint(Square::B2));
+    /// assert!(!BitBoard::from(File::A).is_disjoint(Square::A1));
+    /// ```
+    #[inline]
+    pub fn is_disjoint<T: Into<BitBoard>>(self, other: T) -> bool {
+        (self & other).is_empty()
+    }
+
+    /// Returns whether `self` has exactly one bit set, the same as
+    /// [`is_single`](#method.is_single).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert!(!BitBoard::EMPTY.exactly_one());
+    /// assert!(BitBoard::from(Square::A1).exactly_one());
+    /// assert!(BitBoard::from(Square::H8).exactly_one());
+    /// assert!(!(Square::A1 | Square::H8).exactly_one());
+    /// assert!(!BitBoard::FULL.exactly_one());
+    /// ```
+    #[inline]
+    pub fn exactly_one(self) -> bool {
+        self.is_single()
+    }
+
+    /// Returns the smallest square in `self`, the same as
+    /// [`lsb`](#method.lsb).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let board = Square::C6 | Square::F2;
+    /// assert_eq!(board.smallest(), Some(Square::F2));
+    /// assert_eq!(BitBoard::EMPTY.smallest(), None);
+    /// ```
+    #[inline]
+    pub fn smallest(self) -> Option<Square> {
+        self.lsb()
+    }
+
+    /// Returns the largest square in `self`, the same as
+    /// [`msb`](#method.msb).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let board = Square::C6 | Square::F2;
+    /// assert_eq!(board.largest(), Some(Square::C6));
+    /// assert_eq!(BitBoard::EMPTY.largest(), None);
+    /// ```
+    #[inline]
+    pub fn largest(self) -> Option<Square> {
+        self.msb()
+    }
+
+    /// Returns the square in `self` with the smallest
+    /// [distance](../square/enum.Square.html#method.distance) to `sq`, or
+    /// `None` if `self` is empty.
+    ///
+    /// Ties are broken in favor of the smallest square.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let board = Square::A1 | Square::F5 | Square::H8;
+    /// assert_eq!(board.closest_to(Square::E3), Some(Square::F5));
+    /// assert_eq!(BitBoard::EMPTY.closest_to(Square::E3), None);
+    /// ```
+    #[inline]
+    pub fn closest_to(self, sq: Square) -> Option<Square> {
+        self.into_iter().min_by_key(|&other| other.distance(sq))
+    }
+
+    /// Returns the smallest square in `self` without checking whether `self`
+    /// is empty.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be empty.
+    #[inline]
+    pub unsafe fn smallest_unchecked(self) -> Square {
+        self.lsb_unchecked()
+    }
+
+    /// Returns the largest square in `self` without checking whether `self`
+    /// is empty.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be empty.
+    #[inline]
+    pub unsafe fn largest_unchecked(self) -> Square {
+        self.msb_unchecked()
+    }
+
+    /// Returns `self` with the bits of `other` set.
+    ///
+    /// This is the same as `self | other`, but reads better when chained.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let board = BitBoard::EMPTY.with(Square::A1).with(Square::H8);
+    ///
+    /// assert_eq!(board, Square::A1 | Square::H8);
+    /// ```
+    #[inline]
+    pub fn with<T: Into<BitBoard>>(self, other: T) -> BitBoard {
+        self | other
+    }
+
+    /// Returns `self` with the bits of `other` flipped.
+    ///
+    /// This is the same as `self ^ other`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let board = BitBoard::from(Square::A1).toggled(Square::A1).toggled(Square::C3);
+    ///
+    /// assert_eq!(board, Square::C3.into());
+    /// ```
+    #[inline]
+    pub fn toggled<T: Into<BitBoard>>(self, other: T) -> BitBoard {
+        self ^ other
+    }
+
+    /// Returns `self` without the bits of `other`.
+    ///
+    /// This is the same as `self - other`, or `self & !other`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let attacks = Square::G1.knight_attacks();
+    /// let targets = attacks.without(Square::E2);
+    ///
+    /// assert_eq!(targets, Square::F3 | Square::H3);
+    /// ```
+    #[inline]
+    pub fn without<T: Into<BitBoard>>(self, other: T) -> BitBoard {
+        self - other
+    }
+
+    /// Returns the number of squares in `self` that are not occupied by `own`.
+    ///
+    /// Treating `self` as the attacks of a piece, this is its mobility.
+    ///
+    /// # Examples
+    ///
+    /// A knight on D4 with friendly pieces on two of its destinations:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let attacks = Square::D4.knight_attacks();
+    /// let own     = Square::B3 | Square::F5 | Square::D5;
+    ///
+    /// assert_eq!(attacks.len(), 8);
+    /// assert_eq!(attacks.mobility(own), 6);
+    /// ```
+    #[inline]
+    pub fn mobility(self, own: BitBoard) -> usize {
+        self.without(own).len()
+    }
+
     /// Returns whether `self` has an empty rank.
     #[inline]
     pub fn contains_empty_rank(self) -> bool {
@@ -304,6 +653,22 @@ impl BitBoard {
     }
 
     /// Generates pawn attacks for each of the bits of `self`.
+    ///
+    /// Both diagonals are computed at once for all pawns, with attacks off the
+    /// side of the board masked out.
+    ///
+    /// # Examples
+    ///
+    /// The result is the union of the attacks from each square:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let pawns   = Square::A2 | Square::E4;
+    /// let attacks = Square::A2.pawn_attacks(Color::White)
+    ///             | Square::E4.pawn_attacks(Color::White);
+    ///
+    /// assert_eq!(pawns.pawn_attacks(Color::White), attacks);
+    /// ```
     #[inline]
     pub fn pawn_attacks(self, color: Color) -> BitBoard {
         use self::Direction::*;
@@ -313,7 +678,42 @@ impl BitBoard {
         }
     }
 
+    /// Generates single pawn pushes for each of the bits of `self` into the
+    /// squares of `empty`.
+    ///
+    /// # Examples
+    ///
+    /// Pawns blocked by a piece cannot be pushed:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let pawns = Square::A2 | Square::B2;
+    /// let empty = !BitBoard::from(Square::B3);
+    ///
+    /// assert_eq!(pawns.pawn_pushes(Color::White, empty), Square::A3.into());
+    /// ```
+    #[inline]
+    pub fn pawn_pushes(self, color: Color, empty: BitBoard) -> BitBoard {
+        self.advance(color) & empty
+    }
+
     /// Generates knight attacks for each of the bits of `self`.
+    ///
+    /// The attacks for all bits are computed at once by shifting `self`,
+    /// without any table lookups.
+    ///
+    /// # Examples
+    ///
+    /// The result is the union of the attacks from each square:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let knights = Square::B1 | Square::G1;
+    /// let attacks = Square::B1.knight_attacks() | Square::G1.knight_attacks();
+    ///
+    /// assert_eq!(knights.knight_attacks(), attacks);
+    /// assert_eq!(BitBoard::FULL.knight_attacks(), BitBoard::FULL);
+    /// ```
     #[inline]
     pub fn knight_attacks(self) -> BitBoard {
         let l1 = (self >> 1) & NOT_FILE_H;
@@ -340,6 +740,22 @@ impl BitBoard {
     }
 
     /// Generates king attacks for each of the bits of `self`.
+    ///
+    /// Like [`knight_attacks`](#method.knight_attacks), the attacks for all
+    /// bits are computed at once without any table lookups.
+    ///
+    /// # Examples
+    ///
+    /// The result is the union of the attacks from each square:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let kings   = Square::A1 | Square::E4;
+    /// let attacks = Square::A1.king_attacks() | Square::E4.king_attacks();
+    ///
+    /// assert_eq!(kings.king_attacks(), attacks);
+    /// assert_eq!(BitBoard::FULL.king_attacks(), BitBoard::FULL);
+    /// ```
     #[inline]
     pub fn king_attacks(self) -> BitBoard {
         use self::Direction::*;
@@ -353,6 +769,82 @@ impl BitBoard {
         self.bishop_attacks(empty) | self.rook_attacks(empty)
     }
 
+    /// Returns the bits of `self` that share a file with at least one other
+    /// bit of `self`.
+    ///
+    /// When `self` is a set of pawns of one color, these are its doubled
+    /// pawns.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let pawns   = Square::A2 | Square::A4 | Square::C3 | Square::H2 | Square::H7;
+    /// let doubled = Square::A2 | Square::A4 | Square::H2 | Square::H7;
+    ///
+    /// assert_eq!(pawns.doubled_pawns(), doubled);
+    /// ```
+    #[inline]
+    pub fn doubled_pawns(self) -> BitBoard {
+        use self::Direction::*;
+        let above = self.shift(Up).fill(Up, BitBoard::FULL);
+        let below = self.shift(Down).fill(Down, BitBoard::FULL);
+        self & (above | below)
+    }
+
+    /// Returns the squares strictly ahead of the bits of `self` on the same
+    /// files, from the perspective of `color`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let span = Square::E5 | Square::E6 | Square::E7 | Square::E8;
+    ///
+    /// assert_eq!(BitBoard::from(Square::E4).front_span(Color::White), span);
+    /// ```
+    #[inline]
+    pub fn front_span(self, color: Color) -> BitBoard {
+        let forward = Direction::forward(color);
+        self.shift(forward).fill(forward, BitBoard::FULL)
+    }
+
+    /// Returns the squares strictly behind the bits of `self` on the same
+    /// files, from the perspective of `color`.
+    #[inline]
+    pub fn rear_span(self, color: Color) -> BitBoard {
+        self.front_span(!color)
+    }
+
+    /// Returns the [front span](#method.front_span) of `self` along with the
+    /// front spans of the adjacent files, from the perspective of `color`.
+    ///
+    /// These are the squares that pawns of `color` on the bits of `self` can
+    /// reach or attack. A pawn is passed when it is not within the attack
+    /// front span of any opposing pawn.
+    ///
+    /// # Examples
+    ///
+    /// Finding passed pawns:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let white = Square::A4 | Square::E4 | Square::G5;
+    /// let black = Square::D6 | Square::H7;
+    ///
+    /// let passed = white - black.attack_front_span(Color::Black);
+    /// assert_eq!(passed, Square::A4.into());
+    /// ```
+    #[inline]
+    pub fn attack_front_span(self, color: Color) -> BitBoard {
+        let span = self.front_span(color);
+        span | span.shift(Direction::Left) | span.shift(Direction::Right)
+    }
+
     /// Returns `self` advanced by one rank for `color`.
     #[inline]
     pub fn advance(self, color: Color) -> BitBoard {
@@ -365,6 +857,46 @@ impl BitBoard {
         self.shift(Direction::backward(color))
     }
 
+    /// Returns `self` mirrored vertically, swapping rank 1 with rank 8, rank 2
+    /// with rank 7, and so on.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let board = Square::A1 | Square::C3;
+    /// assert_eq!(board.flip_vertical(), Square::A8 | Square::C6);
+    /// ```
+    #[inline]
+    pub fn flip_vertical(self) -> BitBoard {
+        BitBoard(self.0.swap_bytes())
+    }
+
+    /// Returns `self` from the perspective of `color`.
+    ///
+    /// This is `self` for white and `self` [flipped vertically] for black,
+    /// which allows for tables written from white's perspective to be used for
+    /// either color. This is the same as mapping each square with
+    /// [`Square::relative`](../square/enum.Square.html#method.relative).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(BitBoard::RANK_2.relative(Color::White), BitBoard::RANK_2);
+    /// assert_eq!(BitBoard::RANK_2.relative(Color::Black), BitBoard::RANK_7);
+    /// ```
+    ///
+    /// [flipped vertically]: #method.flip_vertical
+    #[inline]
+    pub fn relative(self, color: Color) -> BitBoard {
+        color.fold(self, self.flip_vertical())
+    }
+
     /// Returns `self` shifted in a direction (relative to white's perspective).
     #[inline]
     pub fn shift(self, direction: Direction) -> BitBoard {
@@ -381,6 +913,86 @@ impl BitBoard {
         }
     }
 
+    /// Returns `self` shifted up by `n` ranks, towards rank 8.
+    ///
+    /// Bits shifted past the edge of the board are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let bits = BitBoard::from(Rank::Two);
+    ///
+    /// assert_eq!(bits.shift_up(2), Rank::Four.into());
+    /// assert_eq!(bits.shift_up(7), BitBoard::EMPTY);
+    /// ```
+    #[inline]
+    pub fn shift_up(self, n: u32) -> BitBoard {
+        if n < 8 { self << (8 * n) } else { BitBoard::EMPTY }
+    }
+
+    /// Returns `self` shifted down by `n` ranks, towards rank 1.
+    ///
+    /// Bits shifted past the edge of the board are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let bits = BitBoard::from(Rank::Seven);
+    ///
+    /// assert_eq!(bits.shift_down(2), Rank::Five.into());
+    /// assert_eq!(bits.shift_down(7), BitBoard::EMPTY);
+    /// ```
+    #[inline]
+    pub fn shift_down(self, n: u32) -> BitBoard {
+        if n < 8 { self >> (8 * n) } else { BitBoard::EMPTY }
+    }
+
+    /// Returns `self` shifted left by `n` files, towards the A file.
+    ///
+    /// Bits shifted past the edge of the board are discarded rather than
+    /// wrapping onto the previous rank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(BitBoard::FULL.shift_left(1), !BitBoard::from(File::H));
+    /// assert_eq!(BitBoard::from(Square::C3).shift_left(2), Square::A3.into());
+    /// ```
+    #[inline]
+    pub fn shift_left(self, n: u32) -> BitBoard {
+        if n < 8 {
+            let files = (0xFF >> n) * masks::FILE_A.0;
+            (self >> n) & BitBoard(files)
+        } else {
+            BitBoard::EMPTY
+        }
+    }
+
+    /// Returns `self` shifted right by `n` files, towards the H file.
+    ///
+    /// Bits shifted past the edge of the board are discarded rather than
+    /// wrapping onto the next rank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(BitBoard::FULL.shift_right(1), !BitBoard::from(File::A));
+    /// assert_eq!(BitBoard::from(Square::F3).shift_right(2), Square::H3.into());
+    /// ```
+    #[inline]
+    pub fn shift_right(self, n: u32) -> BitBoard {
+        if n < 8 {
+            let files = (0xFF << n & 0xFF) * masks::FILE_A.0;
+            (self << n) & BitBoard(files)
+        } else {
+            BitBoard::EMPTY
+        }
+    }
+
     /// Returns `self` filled in a direction (relative to white's perspective),
     /// blocked off by non-empty squares.
     #[inline]
@@ -422,6 +1034,94 @@ impl BitBoard {
         self.fill(direction, empty).shift(direction)
     }
 
+    /// Parses a grid of eight lines with eight characters each, where `#` is a
+    /// set bit and `.` is an unset bit.
+    ///
+    /// The first line is rank 8 and the last is rank 1. Whitespace around the
+    /// grid and around each line is ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let grid = "
+    ///     #.......
+    ///     ........
+    ///     ........
+    ///     ........
+    ///     ........
+    ///     ........
+    ///     ........
+    ///     .......#
+    /// ";
+    ///
+    /// let board = BitBoard::from_grid_str(grid);
+    /// assert_eq!(board, Some(Square::A8 | Square::H1));
+    /// ```
+    pub fn from_grid_str(s: &str) -> Option<BitBoard> {
+        let mut bits  = 0u64;
+        let mut lines = s.trim().lines();
+        for rank in Rank::ALL.rev() {
+            let line = lines.next()?.trim();
+            if line.len() != 8 {
+                return None;
+            }
+            for (file, byte) in line.bytes().enumerate() {
+                let bit = match byte {
+                    b'#' => 1,
+                    b'.' => 0,
+                    _ => return None,
+                };
+                bits |= bit << (((rank as usize) << 3) | file);
+            }
+        }
+        if lines.next().is_some() { None } else { Some(BitBoard(bits)) }
+    }
+
+    /// Writes `self` as a grid that can be parsed by
+    /// [`from_grid_str`](#method.from_grid_str).
+    ///
+    /// # Examples
+    ///
+    /// Writing into a fixed-size buffer without allocating:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// use std::fmt::Write;
+    ///
+    /// struct Buf([u8; 71], usize);
+    ///
+    /// impl Write for Buf {
+    ///     fn write_str(&mut self, s: &str) -> std::fmt::Result {
+    ///         let end = self.1 + s.len();
+    ///         self.0[self.1..end].copy_from_slice(s.as_bytes());
+    ///         self.1 = end;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let board = BitBoard::from(Rank::Two);
+    /// let mut buf = Buf([0; 71], 0);
+    /// board.write_grid(&mut buf).unwrap();
+    ///
+    /// let grid = std::str::from_utf8(&buf.0).unwrap();
+    /// assert_eq!(BitBoard::from_grid_str(grid), Some(board));
+    /// ```
+    pub fn write_grid<W: fmt::Write>(self, w: &mut W) -> fmt::Result {
+        for rank in Rank::ALL.rev() {
+            if rank != Rank::Eight {
+                w.write_char('\n')?;
+            }
+            for file in File::ALL {
+                let ch = if self.contains(Square::new(file, rank)) { '#' } else { '.' };
+                w.write_char(ch)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the result of applying a function to a mutable string
     /// representation of `self`.
     #[inline]
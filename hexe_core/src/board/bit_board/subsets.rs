@@ -146,4 +146,27 @@ mod tests {
         assert_eq!(iter.size_hint(), (0, Some(0)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn distinct() {
+        let superset = Square::A1 | Square::D4 | Square::H8;
+        let mut subsets = [BitBoard::EMPTY; 8];
+        let mut len = 0;
+
+        for subset in superset.subsets() {
+            subsets[len] = subset;
+            len += 1;
+        }
+
+        assert_eq!(len, 8);
+        assert_eq!(subsets[0], BitBoard::EMPTY);
+        assert_eq!(subsets[7], superset);
+
+        for (i, &a) in subsets.iter().enumerate() {
+            assert!(superset.contains(a));
+            for &b in &subsets[(i + 1)..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
 }
@@ -0,0 +1,302 @@
+use super::*;
+use rand::{Rng, thread_rng};
+
+/// Boards covering the empty, single-square, full, and edge cases.
+fn samples() -> [BitBoard; 10] {
+    [
+        BitBoard::EMPTY,
+        BitBoard::FULL,
+        BitBoard::from(Square::A1),
+        BitBoard::from(Square::H8),
+        BitBoard::from(Square::E4),
+        BitBoard::FILE_A,
+        BitBoard::FILE_H,
+        BitBoard::RANK_1 | BitBoard::RANK_8,
+        Square::A8 | Square::H1 | Square::D5,
+        BitBoard::FILE_A | BitBoard::FILE_H | Square::C3 | Square::F6,
+    ]
+}
+
+#[test]
+fn pop_lsb_msb() {
+    let mut board = Square::A8 | Square::F6 | Square::C3;
+    assert_eq!(board.pop_lsb(), Some(Square::C3));
+    assert_eq!(board.pop_msb(), Some(Square::A8));
+    assert_eq!(board.pop_lsb(), Some(Square::F6));
+    assert_eq!(board.pop_msb(), None);
+
+    for &bits in &samples() {
+        let mut board = bits;
+        let mut prev  = None;
+        while let Some(square) = board.pop_lsb() {
+            assert!(bits.contains(square));
+            assert!(!board.contains(square));
+            assert!(prev < Some(square));
+            prev = Some(square);
+        }
+        assert!(board.is_empty());
+        assert_eq!(prev, bits.msb());
+
+        let mut board = bits;
+        let mut prev  = None;
+        while let Some(square) = board.pop_msb() {
+            assert!(prev.map_or(true, |prev| prev > square));
+            prev = Some(square);
+        }
+        assert!(board.is_empty());
+        assert_eq!(prev, bits.lsb());
+    }
+}
+
+#[test]
+fn len() {
+    fn naive(mut bits: u64) -> usize {
+        let mut len = 0;
+        while bits != 0 {
+            len  += (bits & 1) as usize;
+            bits >>= 1;
+        }
+        len
+    }
+
+    let mut rng = thread_rng();
+    for _ in 0..1024 {
+        let board: BitBoard = rng.gen();
+        let len = naive(board.0);
+        assert_eq!(board.len(), len);
+        assert_eq!(board.len_u32() as usize, len);
+        assert_eq!(board.count_ones() as usize, len);
+        assert_eq!(board.is_single(), len == 1);
+        assert_eq!(board.is_single(), board.exactly_one());
+    }
+
+    for square in Square::ALL {
+        assert!(BitBoard::from(square).is_single());
+    }
+    assert!(!BitBoard::EMPTY.is_single());
+    assert_eq!(BitBoard::FULL.len(), 64);
+    assert_eq!(BitBoard::FILE_A.len(), 8);
+}
+
+#[test]
+fn smallest_largest() {
+    let pairs = [
+        (Square::A1, Square::H8),
+        (Square::H1, Square::A8),
+        (Square::E4, Square::E4),
+        (Square::B2, Square::B3),
+        (Square::H7, Square::A8),
+    ];
+    for &(a, b) in &pairs {
+        let board = a | b;
+
+        assert_eq!(board.msb(), Some(a.max(b)));
+        assert_eq!(board.lsb(), Some(a.min(b)));
+        assert_eq!(board.largest(),  board.msb());
+        assert_eq!(board.smallest(), board.lsb());
+        unsafe {
+            assert_eq!(board.largest_unchecked(),  a.max(b));
+            assert_eq!(board.smallest_unchecked(), a.min(b));
+        }
+    }
+    assert_eq!(BitBoard::EMPTY.largest(),  None);
+    assert_eq!(BitBoard::EMPTY.smallest(), None);
+    assert_eq!(BitBoard::FILE_H.largest(),  Some(Square::H8));
+    assert_eq!(BitBoard::FILE_H.smallest(), Some(Square::H1));
+}
+
+#[test]
+fn closest_to() {
+    let board = Square::B2 | Square::G7 | Square::D6;
+    assert_eq!(board.closest_to(Square::H8), Some(Square::G7));
+    assert_eq!(board.closest_to(Square::A1), Some(Square::B2));
+
+    // D6 and G7 are both two squares away; D6 is smaller
+    assert_eq!(board.closest_to(Square::F5), Some(Square::D6));
+
+    assert_eq!(BitBoard::EMPTY.closest_to(Square::E4), None);
+    assert_eq!(BitBoard::FULL.closest_to(Square::E4), Some(Square::E4));
+    assert_eq!((Square::A1 | Square::A8).closest_to(Square::B7), Some(Square::A8));
+    assert_eq!(BitBoard::from(Square::H8).closest_to(Square::A1), Some(Square::H8));
+}
+
+#[test]
+fn from_iter() {
+    assert_eq!(Square::ALL.collect::<BitBoard>(), BitBoard::FULL);
+    assert_eq!(File::ALL.collect::<BitBoard>(),   BitBoard::FULL);
+    assert_eq!(Rank::ALL.collect::<BitBoard>(),   BitBoard::FULL);
+
+    let squares = [Square::A1, Square::D4, Square::H8];
+    let board: BitBoard = squares.iter().cloned().collect();
+    assert_eq!(board, Square::A1 | Square::D4 | Square::H8);
+
+    let mut board = BitBoard::EMPTY;
+    board.extend(BitBoard::from(Rank::Two).chain(Some(Square::E4)));
+    board.extend(Some(File::A));
+    assert_eq!(board, Rank::Two | File::A | Square::E4);
+}
+
+#[test]
+fn grid() {
+    use core::fmt::{self, Write};
+
+    // A fixed-size writer since `String` is unavailable without `std`
+    struct Buf { buf: [u8; 71], len: usize }
+
+    impl Write for Buf {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            if end > self.buf.len() {
+                return Err(fmt::Error);
+            }
+            self.buf[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    for &board in &samples() {
+        let mut buf = Buf { buf: [0; 71], len: 0 };
+        board.write_grid(&mut buf).unwrap();
+
+        let grid = str::from_utf8(&buf.buf[..buf.len]).unwrap();
+        assert_eq!(BitBoard::from_grid_str(grid), Some(board), "{}", grid);
+    }
+
+    static FAILS: &[&str] = &[
+        "",
+        "........",
+        "........\n........\n........\n........\n\
+         ........\n........\n........\n.......",
+        "........\n........\n........\n........\n\
+         ........\n........\n........\n.......1",
+        "........\n........\n........\n........\n\
+         ........\n........\n........\n........\n........",
+    ];
+    for &s in FAILS {
+        assert_eq!(BitBoard::from_grid_str(s), None, "{:?}", s);
+    }
+}
+
+#[test]
+fn pawn_spans() {
+    use self::masks::*;
+
+    assert_eq!(RANK_2.pawn_attacks(Color::White), RANK_3);
+    assert_eq!(RANK_7.pawn_attacks(Color::Black), RANK_6);
+
+    // Edge pawns only attack inward
+    assert_eq!(FILE_A.pawn_attacks(Color::White), FILE_B & !RANK_1);
+    assert_eq!(FILE_H.pawn_attacks(Color::Black), FILE_G & !RANK_8);
+
+    let empty = !(RANK_2 | RANK_7);
+    assert_eq!(RANK_2.pawn_pushes(Color::White, empty), RANK_3);
+    assert_eq!(RANK_7.pawn_pushes(Color::Black, empty), RANK_6);
+
+    let blocked = RANK_3 | Square::E6;
+    assert!(RANK_2.pawn_pushes(Color::White, !blocked).is_empty());
+    assert_eq!(RANK_7.pawn_pushes(Color::Black, !blocked), RANK_6 & !blocked);
+
+    let samples = samples();
+    for (&pawns, &empty) in samples.iter().zip(samples.iter().rev()) {
+        for color in Color::ALL {
+            let attacks = pawns.fold(BitBoard::EMPTY, |acc, sq| {
+                acc | sq.pawn_attacks(color)
+            });
+            let pushes = pawns.fold(BitBoard::EMPTY, |acc, sq| {
+                match sq.pawn_push(color) {
+                    Some(dst) if empty.contains(dst) => acc | dst,
+                    _ => acc,
+                }
+            });
+            assert_eq!(pawns.pawn_attacks(color), attacks);
+            assert_eq!(pawns.pawn_pushes(color, empty), pushes);
+        }
+    }
+}
+
+#[test]
+fn front_spans() {
+    let e4 = BitBoard::from(Square::E4);
+    let ahead = Square::E5 | Square::E6 | Square::E7 | Square::E8;
+    let behind = Square::E3 | Square::E2 | Square::E1;
+
+    assert_eq!(e4.front_span(Color::White), ahead);
+    assert_eq!(e4.rear_span(Color::White), behind);
+    assert_eq!(e4.front_span(Color::Black), behind);
+    assert_eq!(e4.rear_span(Color::Black), ahead);
+
+    let attack = ahead | ahead.shift(Direction::Left) | ahead.shift(Direction::Right);
+    assert_eq!(e4.attack_front_span(Color::White), attack);
+    assert_eq!(attack.len(), 12);
+
+    // Edge files only span inward
+    let a2 = BitBoard::from(Square::A2).attack_front_span(Color::White);
+    assert_eq!(a2, (BitBoard::FILE_A | BitBoard::FILE_B) - (Rank::One | Rank::Two));
+
+    assert!(BitBoard::from(Rank::Eight).front_span(Color::White).is_empty());
+
+    for &bits in &samples() {
+        for color in Color::ALL {
+            let span = bits.fold(BitBoard::EMPTY, |acc, sq| {
+                let mut acc = acc;
+                let mut next = sq.forward(color);
+                while let Some(ahead) = next {
+                    acc |= ahead;
+                    next = ahead.forward(color);
+                }
+                acc
+            });
+            assert_eq!(bits.front_span(color), span);
+        }
+    }
+}
+
+#[test]
+fn relative() {
+    assert_eq!(BitBoard::RANK_2.relative(Color::Black), BitBoard::RANK_7);
+    assert_eq!(BitBoard::RANK_2.flip_vertical(), BitBoard::RANK_7);
+    assert_eq!(BitBoard::FILE_C.flip_vertical(), BitBoard::FILE_C);
+
+    for &bits in &samples() {
+        assert_eq!(bits.flip_vertical().flip_vertical(), bits);
+        for color in Color::ALL {
+            let relative = bits.fold(BitBoard::EMPTY, |acc, sq| acc | sq.relative(color));
+            assert_eq!(bits.relative(color), relative);
+        }
+    }
+}
+
+#[test]
+fn shift_multiple() {
+    use self::masks::*;
+    use misc::Direction::*;
+
+    assert_eq!(BitBoard::FULL.shift_right(1), !FILE_A);
+    assert_eq!(BitBoard::FULL.shift_left(1),  !FILE_H);
+    assert_eq!(BitBoard::FULL.shift_up(1),    !RANK_1);
+    assert_eq!(BitBoard::FULL.shift_down(1),  !RANK_8);
+
+    assert_eq!(BitBoard::FULL.shift_right(7), FILE_H);
+    assert_eq!(BitBoard::FULL.shift_left(7),  FILE_A);
+    assert_eq!(BitBoard::FULL.shift_right(8), BitBoard::EMPTY);
+    assert_eq!(BitBoard::FULL.shift_up(8),    BitBoard::EMPTY);
+
+    for &bits in &samples() {
+        assert_eq!(bits.shift_up(0),    bits);
+        assert_eq!(bits.shift_right(0), bits);
+
+        // Each step is the same as a single directional shift
+        let (mut up, mut down, mut left, mut right) = (bits, bits, bits, bits);
+        for n in 1..9 {
+            up    = up.shift(Up);
+            down  = down.shift(Down);
+            left  = left.shift(Left);
+            right = right.shift(Right);
+            assert_eq!(bits.shift_up(n),    up);
+            assert_eq!(bits.shift_down(n),  down);
+            assert_eq!(bits.shift_left(n),  left);
+            assert_eq!(bits.shift_right(n), right);
+        }
+    }
+}
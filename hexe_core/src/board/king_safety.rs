@@ -0,0 +1,80 @@
+//! King safety patterns for evaluation.
+
+use board::BitBoard;
+use color::Color;
+use square::Square;
+
+/// Returns the squares of the pawn shield in front of a king of `color` on
+/// `king`.
+///
+/// These are the squares one and two ranks ahead of the king on its file and
+/// the files on either side. A king on an edge file only has two files of
+/// shield, and a king near its last rank has fewer ranks.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use hexe_core::prelude::*;
+/// use hexe_core::board::king_safety;
+///
+/// let shield = king_safety::pawn_shield(Square::G1, Color::White);
+///
+/// assert!(shield.contains(Square::F2 | Square::G2 | Square::H2));
+/// assert!(shield.contains(Square::F3 | Square::G3 | Square::H3));
+/// assert_eq!(shield.len(), 6);
+/// ```
+#[inline]
+pub fn pawn_shield(king: Square, color: Color) -> BitBoard {
+    let file  = king.file();
+    let files = (file.adjacent_mask() | file) & king.rank();
+    let one   = files.advance(color);
+    one | one.advance(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prelude::*;
+
+    fn grid(s: &str) -> BitBoard {
+        BitBoard::from_grid_str(s).unwrap()
+    }
+
+    #[test]
+    fn castled_king() {
+        let shield = grid("
+            ........
+            ........
+            ........
+            ........
+            ........
+            .....###
+            .....###
+            ........
+        ");
+        assert_eq!(pawn_shield(Square::G1, Color::White), shield);
+        assert_eq!(pawn_shield(Square::G8, Color::Black), shield.relative(Color::Black));
+    }
+
+    #[test]
+    fn edge_king() {
+        let shield = grid("
+            ........
+            ........
+            ........
+            ........
+            ........
+            ......##
+            ......##
+            ........
+        ");
+        assert_eq!(pawn_shield(Square::H1, Color::White), shield);
+        assert_eq!(pawn_shield(Square::A8, Color::Black).len(), 4);
+
+        // Only one rank fits ahead of a king on its seventh rank
+        assert_eq!(pawn_shield(Square::H7, Color::White), Square::G8 | Square::H8);
+        assert!(pawn_shield(Square::E8, Color::White).is_empty());
+    }
+}
@@ -90,6 +90,8 @@
 pub mod bit_board;
 pub mod multi_board;
 pub mod piece_map;
+pub mod pawns;
+pub mod king_safety;
 
 #[doc(inline)] pub use self::bit_board::BitBoard;
 #[doc(inline)] pub use self::multi_board::MultiBoard;
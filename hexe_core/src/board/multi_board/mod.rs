@@ -9,6 +9,7 @@ use uncon::*;
 use prelude::*;
 use board::PieceMap;
 use castle;
+use misc::Direction;
 
 #[cfg(all(test, nightly))]
 mod benches;
@@ -50,13 +51,42 @@ impl PartialEq for MultiBoard {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         #[cfg(feature = "simd")]
-        { self as *const _ == other as *const _ || self.simd() == other.simd() }
+        { self as *const _ == other as *const _ || simd_eq(self.bytes(), other.bytes()) }
 
         #[cfg(not(feature = "simd"))]
         { self.bytes()[..] == other.bytes()[..] }
     }
 }
 
+/// Compares `a` and `b` one vector at a time, followed by any trailing bytes
+/// that do not fill an entire vector.
+#[cfg(feature = "simd")]
+#[inline]
+fn simd_eq(a: &[u8; NUM_BYTES], b: &[u8; NUM_BYTES]) -> bool {
+    chunks_eq(a, b, 64, |a, b| {
+        u8x64::from_slice_unaligned(a) == u8x64::from_slice_unaligned(b)
+    })
+}
+
+/// Compares `a` and `b` with `eq` in chunks of `lanes` bytes, and compares
+/// any remaining tail bytes directly.
+#[cfg(any(test, feature = "simd"))]
+#[inline]
+fn chunks_eq<F>(a: &[u8], b: &[u8], lanes: usize, eq: F) -> bool
+    where F: Fn(&[u8], &[u8]) -> bool
+{
+    if a.len() != b.len() {
+        return false;
+    }
+    let split = a.len() - a.len() % lanes;
+
+    let (a_head, a_tail) = a.split_at(split);
+    let (b_head, b_tail) = b.split_at(split);
+
+    a_head.chunks(lanes).zip(b_head.chunks(lanes)).all(|(a, b)| eq(a, b))
+        && a_tail == b_tail
+}
+
 impl Default for MultiBoard {
     #[inline]
     fn default() -> MultiBoard {
@@ -100,9 +130,7 @@ impl<'a> From<&'a PieceMap> for MultiBoard {
     #[inline]
     fn from(map: &PieceMap) -> MultiBoard {
         let mut board = MultiBoard::default();
-        for (square, &piece) in map {
-            board.insert_unchecked(square, piece);
-        }
+        board.set_from_map(map);
         board
     }
 }
@@ -150,11 +178,9 @@ impl MultiBoard {
     /// The board for standard chess.
     pub const STANDARD: MultiBoard = values::STANDARD;
 
-    #[cfg(feature = "simd")]
-    #[inline]
-    fn simd(&self) -> u8x64 {
-        u8x64::from_slice_unaligned(self.bytes())
-    }
+    /// The game phase of the standard starting position, as returned by
+    /// [`phase`](#method.phase).
+    pub const MAX_PHASE: u8 = 24;
 
     #[inline]
     fn bytes(&self) -> &[u8; NUM_BYTES] {
@@ -167,6 +193,31 @@ impl MultiBoard {
         unsafe { ::util::zero(self) }
     }
 
+    /// Clears the board and fills it with the pieces of `map`.
+    ///
+    /// This is the same as assigning `MultiBoard::from(map)` to `self`, but
+    /// without building a separate board.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe_core::board::{MultiBoard, PieceMap};
+    ///
+    /// let mut board = MultiBoard::default();
+    /// board.set_from_map(&PieceMap::STANDARD);
+    ///
+    /// assert!(board == MultiBoard::STANDARD);
+    /// ```
+    #[inline]
+    pub fn set_from_map(&mut self, map: &PieceMap) {
+        self.clear();
+        for (square, &piece) in map {
+            self.insert_unchecked(square, piece);
+        }
+    }
+
     /// Returns whether `self` is empty.
     ///
     /// For much better performance and readability, is recommended to use this
@@ -224,6 +275,44 @@ impl MultiBoard {
         BitBoard(w | b)
     }
 
+    /// Returns the squares occupied by pieces in `self`.
+    ///
+    /// This is the same as [`all_bits`](#method.all_bits).
+    #[inline]
+    pub fn occupied(&self) -> BitBoard {
+        self.all_bits()
+    }
+
+    /// Returns the squares occupied by pieces of `color`.
+    #[inline]
+    pub fn occupied_by(&self, color: Color) -> BitBoard {
+        self.bits(color)
+    }
+
+    /// Returns the squares that are not occupied by any piece.
+    ///
+    /// This is all 64 squares minus those that are [`occupied`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe_core::board::MultiBoard;
+    /// use hexe_core::prelude::*;
+    ///
+    /// let board = MultiBoard::STANDARD;
+    /// let empty = Rank::Three | Rank::Four | Rank::Five | Rank::Six;
+    ///
+    /// assert_eq!(board.empty(), empty);
+    /// ```
+    ///
+    /// [`occupied`]: #method.occupied
+    #[inline]
+    pub fn empty(&self) -> BitBoard {
+        !self.all_bits()
+    }
+
     /// Returns the `BitBoard` for `value` in `self`.
     ///
     /// # Examples
@@ -276,6 +365,34 @@ impl MultiBoard {
         self.bits(value).msb_unchecked()
     }
 
+    /// Returns the piece at `sq`, if any.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe_core::board::MultiBoard;
+    /// use hexe_core::prelude::*;
+    ///
+    /// let board = MultiBoard::STANDARD;
+    ///
+    /// assert_eq!(board.piece_at(Square::E1), Some(Piece::WhiteKing));
+    /// assert_eq!(board.piece_at(Square::E4), None);
+    /// ```
+    pub fn piece_at(&self, sq: Square) -> Option<Piece> {
+        let color = if self[Color::White].contains(sq) {
+            Color::White
+        } else if self[Color::Black].contains(sq) {
+            Color::Black
+        } else {
+            return None;
+        };
+        Role::ALL.into_iter()
+                 .find(|&role| self[role].contains(sq))
+                 .map(|role| Piece::new(role, color))
+    }
+
     /// Returns the total number of `value` in `self`.
     ///
     /// # Examples
@@ -297,6 +414,82 @@ impl MultiBoard {
         self.bits(value).len()
     }
 
+    /// Returns the number of `piece` on the board.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe_core::board::MultiBoard;
+    /// use hexe_core::prelude::*;
+    ///
+    /// let board = MultiBoard::STANDARD;
+    ///
+    /// assert_eq!(board.count_piece(Piece::WhitePawn), 8);
+    /// assert_eq!(board.count_piece(Piece::BlackQueen), 1);
+    /// ```
+    #[inline]
+    pub fn count_piece(&self, piece: Piece) -> usize {
+        (self[piece.color()] & self[piece.role()]).len()
+    }
+
+    /// Returns the total material value of the pieces of `color` in
+    /// centipawns, excluding the king.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe_core::board::MultiBoard;
+    /// use hexe_core::prelude::*;
+    ///
+    /// let board = MultiBoard::STANDARD;
+    ///
+    /// assert_eq!(board.material(Color::White), board.material(Color::Black));
+    /// ```
+    pub fn material(&self, color: Color) -> i32 {
+        let mut material = 0;
+        for role in Role::ALL {
+            if role != Role::King {
+                let count = self.count_piece(Piece::new(role, color)) as i32;
+                material += count * role.value() as i32;
+            }
+        }
+        material
+    }
+
+    /// Returns the game phase based on the remaining non-pawn material, for
+    /// use in [tapered evaluation][wiki].
+    ///
+    /// Each knight and bishop counts as 1, each rook as 2, and each queen as 4.
+    /// The result ranges from 0 for a bare endgame to
+    /// [`MAX_PHASE`](#associatedconstant.MAX_PHASE) for the opening. It is
+    /// capped at `MAX_PHASE` when promotions add extra material.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe_core::board::MultiBoard;
+    ///
+    /// assert_eq!(MultiBoard::STANDARD.phase(), MultiBoard::MAX_PHASE);
+    /// assert_eq!(MultiBoard::default().phase(), 0);
+    /// ```
+    ///
+    /// [wiki]: https://www.chessprogramming.org/Tapered_Eval
+    pub fn phase(&self) -> u8 {
+        let minors = self.count(Role::Knight) + self.count(Role::Bishop);
+        let phase  = minors + 2 * self.count(Role::Rook) + 4 * self.count(Role::Queen);
+        if phase > Self::MAX_PHASE as usize {
+            Self::MAX_PHASE
+        } else {
+            phase as u8
+        }
+    }
+
     /// Returns whether `value` is contained at all squares in `bits`.
     ///
     /// # Examples
@@ -363,6 +556,39 @@ impl MultiBoard {
         self[piece.role() ] |= value;
     }
 
+    /// Toggles `piece` at `sq` using [XOR].
+    ///
+    /// If `sq` is empty, `piece` is placed there. If `piece` is at `sq`, it is
+    /// removed. This makes the method its own inverse, which is useful for
+    /// making and unmaking moves.
+    ///
+    /// The caller must ensure that `sq` is either empty or holds `piece`.
+    /// Otherwise, the board will be left in an invalid state.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe_core::board::MultiBoard;
+    /// use hexe_core::prelude::*;
+    ///
+    /// let mut board = MultiBoard::STANDARD;
+    ///
+    /// board.toggle(Square::E2, Piece::WhitePawn);
+    /// board.toggle(Square::E4, Piece::WhitePawn);
+    /// assert!(board.contains(Square::E4, Piece::WhitePawn));
+    /// assert!(!board.all_bits().contains(Square::E2));
+    /// ```
+    ///
+    /// [XOR]: https://en.wikipedia.org/wiki/Exclusive_or
+    #[inline]
+    pub fn toggle(&mut self, sq: Square, piece: Piece) {
+        let bit = BitBoard::from(sq);
+        self[piece.color()] ^= bit;
+        self[piece.role() ] ^= bit;
+    }
+
     /// Removes each piece at `bits` for `value`.
     #[inline]
     pub fn remove<T, U>(&mut self, bits: T, value: U)
@@ -431,6 +657,39 @@ impl MultiBoard {
         unsafe { (&mut *colors, &mut *pieces) }
     }
 
+    /// Returns the squares attacked by the pawns of `color` toward the H file.
+    ///
+    /// Each attacked square lies one file east of the pawn that attacks it.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe_core::board::MultiBoard;
+    /// use hexe_core::prelude::*;
+    ///
+    /// let board   = MultiBoard::STANDARD;
+    /// let attacks = board.pawn_attacks_east(Color::White);
+    ///
+    /// assert!(attacks.contains(Square::B3));
+    /// assert!(!attacks.contains(Square::A3));
+    /// ```
+    #[inline]
+    pub fn pawn_attacks_east(&self, color: Color) -> BitBoard {
+        let pawns = self.bits(Piece::new(Role::Pawn, color));
+        pawns.advance(color).shift(Direction::Right)
+    }
+
+    /// Returns the squares attacked by the pawns of `color` toward the A file.
+    ///
+    /// Each attacked square lies one file west of the pawn that attacks it.
+    #[inline]
+    pub fn pawn_attacks_west(&self, color: Color) -> BitBoard {
+        let pawns = self.bits(Piece::new(Role::Pawn, color));
+        pawns.advance(color).shift(Direction::Left)
+    }
+
     /// Returns whether the square for `player` is being attacked.
     ///
     /// This method _does not_ check whether a piece for `player` actually
@@ -461,6 +720,100 @@ impl MultiBoard {
         rooks.intersects(sq.rook_attacks(all))
     }
 
+    /// Returns the pieces of both colors that attack `sq`, treating `occupied`
+    /// as the set of pieces that block sliding attacks.
+    ///
+    /// Only pieces within `occupied` are returned. This allows for revealing
+    /// x-ray attackers by removing pieces from `occupied`, such as when
+    /// performing a static exchange evaluation.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe_core::board::MultiBoard;
+    /// use hexe_core::prelude::*;
+    ///
+    /// let board = MultiBoard::STANDARD;
+    /// let all   = board.all_bits();
+    ///
+    /// let exp = Square::E2 | Square::G2 | Square::G1;
+    /// assert_eq!(board.attackers_to(Square::F3, all), exp);
+    /// ```
+    pub fn attackers_to(&self, sq: Square, occupied: BitBoard) -> BitBoard {
+        let queens  = self.bits(Role::Queen);
+        let bishops = self.bits(Role::Bishop) | queens;
+        let rooks   = self.bits(Role::Rook)   | queens;
+
+        let attackers = sq.pawn_attacks(Color::Black) & self.bits(Piece::WhitePawn)
+                      | sq.pawn_attacks(Color::White) & self.bits(Piece::BlackPawn)
+                      | sq.knight_attacks()           & self.bits(Role::Knight)
+                      | sq.king_attacks()             & self.bits(Role::King)
+                      | sq.bishop_attacks(occupied)   & bishops
+                      | sq.rook_attacks(occupied)     & rooks;
+
+        attackers & occupied
+    }
+
+    /// Performs a **blind** application of `mv` for the `mover`'s `moved` piece
+    /// role, removing the `captured` piece role, if any.
+    ///
+    /// This handles each kind of move: the promotion piece replaces the pawn,
+    /// an en passant capture removes the pawn behind the destination, and
+    /// castling moves the rook along with the king. For castling, `moved` and
+    /// `captured` are ignored.
+    ///
+    /// # Undo-Redo
+    ///
+    /// Because this method internally uses [XOR], it is its own inverse.
+    /// Calling it again with the same arguments restores the previous board.
+    ///
+    /// ```
+    /// use hexe_core::board::MultiBoard;
+    /// use hexe_core::prelude::*;
+    ///
+    /// let mut board = MultiBoard::STANDARD;
+    /// let mv = Move::normal(Square::G1, Square::F3);
+    ///
+    /// board.apply(mv, Color::White, Role::Knight, None);
+    /// assert!(board.contains(Square::F3, Piece::WhiteKnight));
+    ///
+    /// board.apply(mv, Color::White, Role::Knight, None);
+    /// assert!(board == MultiBoard::STANDARD);
+    /// ```
+    ///
+    /// [XOR]: https://en.wikipedia.org/wiki/Exclusive_or
+    pub fn apply(&mut self, mv: Move, mover: Color, moved: Role, captured: Option<Role>) {
+        use mv::Matches;
+
+        let src = BitBoard::from(mv.src());
+        let dst = BitBoard::from(mv.dst());
+
+        let capture = match mv.matches() {
+            Matches::Castle(mv) => return self.castle(mv.right()),
+            Matches::Normal(_) => {
+                self[moved] ^= src | dst;
+                dst
+            },
+            Matches::Promotion(mv) => {
+                self[moved] ^= src;
+                self[Role::from(mv.piece())] ^= dst;
+                dst
+            },
+            Matches::EnPassant(mv) => {
+                self[moved] ^= src | dst;
+                BitBoard::from(mv.capture())
+            },
+        };
+        self[mover] ^= src | dst;
+
+        if let Some(role) = captured {
+            self[!mover] ^= capture;
+            self[role]   ^= capture;
+        }
+    }
+
     /// Performs a **blind** castle of the pieces for the castling right.
     ///
     /// # Invariants
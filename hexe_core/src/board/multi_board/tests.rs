@@ -1,6 +1,7 @@
 use board::PieceMap;
-use super::MultiBoard;
+use super::{MultiBoard, NUM_BYTES, chunks_eq};
 use prelude::*;
+use rand::{Rng, thread_rng};
 
 #[test]
 fn is_attacked() {
@@ -33,9 +34,219 @@ fn is_attacked() {
     iter!(Color::Black, Square::ALL.rev());
 }
 
+#[test]
+fn pawn_attacks_east_west() {
+    let board = MultiBoard::STANDARD;
+    let third = BitBoard::from(Rank::Three);
+
+    let east = board.pawn_attacks_east(Color::White);
+    let west = board.pawn_attacks_west(Color::White);
+
+    assert_eq!(east, third & !BitBoard::from(File::A));
+    assert_eq!(west, third & !BitBoard::from(File::H));
+    assert_eq!(east | west, board.bits(Piece::WhitePawn).pawn_attacks(Color::White));
+
+    for sq in east {
+        assert!(board.contains(sq.left().unwrap().down().unwrap(), Piece::WhitePawn));
+    }
+    for sq in west {
+        assert!(board.contains(sq.right().unwrap().down().unwrap(), Piece::WhitePawn));
+    }
+}
+
+#[test]
+fn piece_at() {
+    let board  = MultiBoard::STANDARD;
+    let pieces = PieceMap::STANDARD;
+
+    for square in Square::ALL {
+        assert_eq!(board.piece_at(square), pieces.get(square).cloned());
+    }
+}
+
+#[test]
+fn attackers_to() {
+    let pieces = PieceMap::from_fen("4k3/8/2n5/3p4/1B2P3/8/8/R2QK3").unwrap();
+    let board  = MultiBoard::from(&pieces);
+    let all    = board.all_bits();
+
+    // Pawn and knight attackers of both colors
+    assert_eq!(board.attackers_to(Square::E4, all), Square::D5.into());
+    assert_eq!(board.attackers_to(Square::B4, all), Square::C6.into());
+    assert_eq!(board.attackers_to(Square::D5, all), Square::E4 | Square::D1);
+
+    // Sliding attackers are blocked by occupancy
+    assert_eq!(board.attackers_to(Square::D4, all), Square::D1 | Square::C6);
+    assert_eq!(board.attackers_to(Square::A4, all), Square::A1 | Square::D1);
+    assert_eq!(board.attackers_to(Square::A5, all), Square::A1 | Square::B4 | Square::C6);
+
+    // Removing a blocker reveals an x-ray attacker
+    let occ = all ^ Square::D5;
+    assert_eq!(board.attackers_to(Square::D7, all), Square::E8.into());
+    assert_eq!(board.attackers_to(Square::D7, occ), Square::E8 | Square::D1);
+
+    // King attackers
+    assert_eq!(board.attackers_to(Square::F2, all), Square::E1.into());
+    assert_eq!(board.attackers_to(Square::F8, all), Square::E8 | Square::B4);
+    assert_eq!(board.attackers_to(Square::D2, all), Square::D1 | Square::E1 | Square::B4);
+}
+
+#[test]
+fn apply() {
+    use mv::kind;
+
+    let pieces = PieceMap::from_fen("r3k3/1P6/8/3pP3/8/8/8/4K2R").unwrap();
+    let board  = MultiBoard::from(&pieces);
+
+    let promotion = kind::Promotion::try_new(Square::B7, Square::A8, Promotion::Knight).unwrap();
+    let moves = [
+        (Move::normal(Square::E1, Square::D2),  Role::King, None,             "r3k3/1P6/8/3pP3/8/8/3K4/7R"),
+        (Move::normal(Square::H1, Square::H8),  Role::Rook, None,             "r3k2R/1P6/8/3pP3/8/8/8/4K3"),
+        (Move::promotion(File::B, Color::White, Promotion::Queen),
+                                                Role::Pawn, None,             "rQ2k3/8/8/3pP3/8/8/8/4K2R"),
+        (promotion.into(),                      Role::Pawn, Some(Role::Rook), "N3k3/8/8/3pP3/8/8/8/4K2R"),
+        (Move::en_passant(Square::E5, Square::D6).unwrap(),
+                                                Role::Pawn, Some(Role::Pawn), "r3k3/1P6/3P4/8/8/8/8/4K2R"),
+        (Move::castle(Right::WhiteKing),        Role::King, None,             "r3k3/1P6/8/3pP3/8/8/8/5RK1"),
+    ];
+
+    for &(mv, moved, captured, fen) in &moves {
+        let exp = MultiBoard::from(&PieceMap::from_fen(fen).unwrap());
+        let mut res = board.clone();
+
+        res.apply(mv, Color::White, moved, captured);
+        assert!(res == exp, "{:?}", mv);
+
+        res.apply(mv, Color::White, moved, captured);
+        assert!(res == board, "{:?}", mv);
+    }
+}
+
 #[test]
 fn from_piece_map() {
     let pieces = PieceMap::STANDARD;
     let board  = MultiBoard::from(&pieces);
     assert!(board == MultiBoard::STANDARD);
 }
+
+#[test]
+fn toggle() {
+    let mut rng = thread_rng();
+    let map = PieceMap::STANDARD;
+
+    for _ in 0..64 {
+        let square: Square = rng.gen();
+        let piece:  Piece  = rng.gen();
+
+        let mut board = MultiBoard::STANDARD;
+        board.toggle(square, piece);
+        board.toggle(square, piece);
+        assert!(board == MultiBoard::STANDARD);
+
+        // `insert` is the same as clearing the square and toggling
+        let mut exp = MultiBoard::STANDARD;
+        exp.insert(square, piece);
+
+        if let Some(&pc) = map.get(square) {
+            board.toggle(square, pc);
+        }
+        board.toggle(square, piece);
+        assert!(board == exp);
+        assert_eq!(board.piece_at(square), Some(piece));
+    }
+}
+
+#[test]
+fn occupied_empty() {
+    let board = MultiBoard::STANDARD;
+    assert_eq!(board.occupied() | board.empty(), BitBoard::FULL);
+    assert!(!board.occupied().intersects(board.empty()));
+
+    for color in Color::ALL {
+        let occupied = board.occupied_by(color);
+        assert_eq!(occupied.len(), 16);
+        assert!(board.occupied().contains(occupied));
+    }
+
+    let board = MultiBoard::default();
+    assert_eq!(board.empty(), BitBoard::FULL);
+}
+
+#[test]
+fn material_and_phase() {
+    let board = MultiBoard::STANDARD;
+    for color in Color::ALL {
+        assert_eq!(board.material(color), 8 * 100 + 2 * (320 + 330 + 500) + 900);
+    }
+    assert_eq!(board.material(Color::White) - board.material(Color::Black), 0);
+    assert_eq!(board.phase(), MultiBoard::MAX_PHASE);
+
+    for piece in (0..12u8).map(Piece::from) {
+        assert_eq!(board.count_piece(piece), board.count(piece));
+    }
+
+    // Kings and pawns only
+    let mut endgame = board.clone();
+    for &role in &[Role::Knight, Role::Bishop, Role::Rook, Role::Queen] {
+        endgame.remove_all(board.bits(role));
+    }
+    assert_eq!(endgame.phase(), 0);
+    assert_eq!(endgame.material(Color::White), 800);
+
+    // Removing a queen and a knight
+    let mut middlegame = board.clone();
+    middlegame.remove_all(Square::D1 | Square::G8);
+    assert_eq!(middlegame.phase(), MultiBoard::MAX_PHASE - 5);
+    assert_eq!(middlegame.material(Color::White) - middlegame.material(Color::Black), -580);
+}
+
+#[test]
+fn eq_every_byte() {
+    fn bytes_mut(board: &mut MultiBoard) -> &mut [u8; NUM_BYTES] {
+        unsafe { &mut *(board as *mut MultiBoard as *mut [u8; NUM_BYTES]) }
+    }
+
+    let board = MultiBoard::STANDARD;
+    assert!(board == board.clone());
+
+    // Guards against any comparison path skipping a trailing byte
+    for &i in &[0, NUM_BYTES / 2, NUM_BYTES - 1] {
+        let mut other = board.clone();
+        bytes_mut(&mut other)[i] ^= 1;
+        assert!(board != other, "byte {} ignored", i);
+        assert!(other != board, "byte {} ignored", i);
+    }
+}
+
+#[test]
+fn chunks_eq_tail() {
+    // 70 bytes leaves a 6-byte tail after a single 64-byte chunk
+    let a = [7u8; 70];
+    let eq = |a: &[u8], b: &[u8]| a == b;
+    assert!(chunks_eq(&a, &a, 64, eq));
+
+    for &i in &[0, 63, 64, 69] {
+        let mut b = a;
+        b[i] ^= 1;
+        assert!(!chunks_eq(&a, &b, 64, eq), "byte {} ignored", i);
+    }
+    assert!(!chunks_eq(&a, &a[..69], 64, eq));
+}
+
+#[test]
+fn set_from_map() {
+    let mut board = MultiBoard::STANDARD;
+    board.remove_all(Square::E2 | Square::D8);
+    board.insert(Square::E4, Piece::WhitePawn);
+
+    board.clear();
+    board.set_from_map(&PieceMap::STANDARD);
+    assert!(board == MultiBoard::STANDARD);
+
+    // Pieces already on the board are replaced rather than merged
+    let mut map = PieceMap::new();
+    map.insert(Square::E4, Piece::BlackQueen);
+    board.set_from_map(&map);
+    assert!(board == MultiBoard::from(&map));
+    assert_eq!(board.len(), 1);
+}
@@ -0,0 +1,159 @@
+//! Pawn structure detection over sets of pawns.
+//!
+//! Each function operates on all pawns at once, making them suitable for
+//! evaluation terms.
+
+use board::BitBoard;
+use color::Color;
+use misc::Direction;
+
+/// Returns every square on the files containing a bit of `bits`.
+#[inline]
+fn file_fill(bits: BitBoard) -> BitBoard {
+    bits.fill(Direction::Up, BitBoard::FULL) | bits.fill(Direction::Down, BitBoard::FULL)
+}
+
+/// Returns `bits` spread onto the files on either side of each bit.
+#[inline]
+fn adjacent_files(bits: BitBoard) -> BitBoard {
+    bits.shift(Direction::Left) | bits.shift(Direction::Right)
+}
+
+/// Returns the pawns of `pawns` with no other pawns of `pawns` on adjacent
+/// files.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use hexe_core::prelude::*;
+/// use hexe_core::board::pawns;
+///
+/// let pawns = Square::A2 | Square::C2 | Square::D3 | Square::H4;
+/// assert_eq!(pawns::isolated_pawns(pawns), Square::A2 | Square::H4);
+/// ```
+#[inline]
+pub fn isolated_pawns(pawns: BitBoard) -> BitBoard {
+    pawns & !adjacent_files(file_fill(pawns))
+}
+
+/// Returns the pawns of `own` for `color` with no pawns of `enemy` ahead of
+/// them on the same or adjacent files.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use hexe_core::prelude::*;
+/// use hexe_core::board::pawns;
+///
+/// let white = Square::A5 | Square::D4 | Square::G2;
+/// let black = Square::B7 | Square::C3 | Square::G4;
+///
+/// // The pawn on A5 is stopped by B7, and G2 by G4
+/// assert_eq!(pawns::passed_pawns(white, black, Color::White), Square::D4.into());
+/// assert_eq!(pawns::passed_pawns(black, white, Color::Black), Square::C3.into());
+/// ```
+#[inline]
+pub fn passed_pawns(own: BitBoard, enemy: BitBoard, color: Color) -> BitBoard {
+    // The squares in front of each enemy pawn, from the enemy's perspective
+    let toward = Direction::forward(!color);
+    let front  = enemy.shift(toward).fill(toward, BitBoard::FULL);
+    own & !(front | adjacent_files(front))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prelude::*;
+
+    fn grid(s: &str) -> BitBoard {
+        BitBoard::from_grid_str(s).unwrap()
+    }
+
+    #[test]
+    fn doubled_and_isolated() {
+        let pawns = grid("
+            ........
+            ........
+            ........
+            ..#....#
+            ........
+            #.#...#.
+            #...#..#
+            ........
+        ");
+        let doubled = grid("
+            ........
+            ........
+            ........
+            ..#....#
+            ........
+            #.#.....
+            #......#
+            ........
+        ");
+        let isolated = grid("
+            ........
+            ........
+            ........
+            ..#.....
+            ........
+            #.#.....
+            #...#...
+            ........
+        ");
+        assert_eq!(pawns.doubled_pawns(), doubled);
+        assert_eq!(isolated_pawns(pawns), isolated);
+
+        assert!(BitBoard::EMPTY.doubled_pawns().is_empty());
+        assert!(isolated_pawns(BitBoard::EMPTY).is_empty());
+        assert!(isolated_pawns(BitBoard::from(Rank::Two)).is_empty());
+        assert!(BitBoard::from(Rank::Two).doubled_pawns().is_empty());
+    }
+
+    #[test]
+    fn passed() {
+        let white = grid("
+            ........
+            .#......
+            ........
+            ....#...
+            #.......
+            ........
+            .....##.
+            ........
+        ");
+        let black = grid("
+            ........
+            ........
+            .....#..
+            ........
+            ........
+            ...#....
+            ........
+            ........
+        ");
+
+        // E5, F2, and G2 each have F6 ahead on the same or an adjacent file
+        let white_passed = grid("
+            ........
+            .#......
+            ........
+            ........
+            #.......
+            ........
+            ........
+            ........
+        ");
+        assert_eq!(passed_pawns(white, black, Color::White), white_passed);
+
+        // No white pawn is below D3 on the C through E files
+        assert_eq!(passed_pawns(black, white, Color::Black), Square::D3.into());
+
+        // Pawns with nothing to oppose them are always passed
+        assert_eq!(passed_pawns(white, BitBoard::EMPTY, Color::White), white);
+    }
+}
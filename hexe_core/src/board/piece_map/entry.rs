@@ -160,6 +160,49 @@ impl<'a> Entry<'a> {
         }
     }
 
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// # use hexe_core::board::PieceMap;
+    /// let mut map = PieceMap::STANDARD;
+    ///
+    /// // Promote the E2 pawn in place
+    /// map.entry(Square::E2)
+    ///    .and_modify(|pc| *pc = Piece::WhiteQueen)
+    ///    .or_insert(Piece::WhiteKnight);
+    /// assert_eq!(map.get(Square::E2), Some(&Piece::WhiteQueen));
+    ///
+    /// map.entry(Square::E4)
+    ///    .and_modify(|pc| *pc = Piece::WhiteQueen)
+    ///    .or_insert(Piece::WhiteKnight);
+    /// assert_eq!(map.get(Square::E4), Some(&Piece::WhiteKnight));
+    /// ```
+    #[inline]
+    pub fn and_modify<F>(self, f: F) -> Entry<'a>
+        where F: FnOnce(&mut Piece)
+    {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            },
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+
+    /// Takes the piece of the entry out of the map, if any.
+    #[inline]
+    pub fn remove(self) -> Option<Piece> {
+        match self {
+            Entry::Occupied(entry) => Some(entry.remove()),
+            Entry::Vacant(_) => None,
+        }
+    }
+
     /// Returns a reference to this entry's square.
     #[inline]
     pub fn key(&self) -> &Square {
@@ -151,3 +151,48 @@ impl<'a> fmt::Debug for IterMut<'a> {
         Iter { map: self.map, iter: self.iter.clone() }.fmt(f)
     }
 }
+
+/// An iterator over the squares and pieces of one color in a
+/// [`PieceMap`](struct.PieceMap.html).
+#[derive(Clone)]
+pub struct IterColor<'a> {
+    pub(super) map: &'a PieceMap,
+    pub(super) bits: BitBoard,
+}
+
+impl<'a> Iterator for IterColor<'a> {
+    type Item = (Square, Piece);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let sq = self.bits.pop_lsb()?;
+        Some((sq, unsafe { *self.map.get_unchecked(sq) }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for IterColor<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let sq = self.bits.pop_msb()?;
+        Some((sq, unsafe { *self.map.get_unchecked(sq) }))
+    }
+}
+
+impl<'a> ExactSizeIterator for IterColor<'a> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.bits.len()
+    }
+}
+
+impl<'a> fmt::Debug for IterColor<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
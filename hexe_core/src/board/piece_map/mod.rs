@@ -5,6 +5,7 @@ use core::{fmt, hash, mem, ops, ptr, str};
 #[cfg(feature = "simd")]
 use packed_simd::u8x64;
 
+use board::MultiBoard;
 use castle;
 use misc::Contained;
 use piece::Piece;
@@ -122,6 +123,17 @@ impl FromUnchecked<u8x64> for PieceMap {
     }
 }
 
+impl<'a> From<&'a MultiBoard> for PieceMap {
+    #[inline]
+    fn from(board: &MultiBoard) -> PieceMap {
+        let mut map = PieceMap::new();
+        for piece in Piece::ALL {
+            map.insert_all(board.bits(piece), piece);
+        }
+        map
+    }
+}
+
 impl From<Array> for PieceMap {
     #[inline]
     fn from(array: Array) -> PieceMap {
@@ -429,6 +441,29 @@ impl PieceMap {
         self.replace(loc, Some(pc))
     }
 
+    /// Inserts the piece at every square in `bits`, replacing any previous
+    /// pieces.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// # use hexe_core::board::PieceMap;
+    /// let mut map = PieceMap::new();
+    /// map.insert_all(Rank::Two.into(), Piece::WhitePawn);
+    ///
+    /// assert_eq!(map.len(), 8);
+    /// assert_eq!(map.get(Square::E2), Some(&Piece::WhitePawn));
+    /// ```
+    #[inline]
+    pub fn insert_all(&mut self, bits: BitBoard, piece: Piece) {
+        for square in bits {
+            self.insert(square, piece);
+        }
+    }
+
     /// Removes all pieces at `loc` and returns them, if any.
     ///
     /// # Examples
@@ -544,6 +579,31 @@ impl PieceMap {
         }
     }
 
+    /// Returns the map with its ranks reversed and the colors of its pieces
+    /// swapped.
+    ///
+    /// This is the same position as seen from the opponent's side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::board::PieceMap;
+    /// # use hexe_core::prelude::*;
+    /// let mut map = PieceMap::new();
+    /// map.insert(Square::E2, Piece::WhitePawn);
+    ///
+    /// let mirror = map.mirror();
+    /// assert_eq!(mirror.get(Square::E7), Some(&Piece::BlackPawn));
+    /// assert_eq!(mirror.len(), 1);
+    /// ```
+    pub fn mirror(&self) -> PieceMap {
+        let mut map = PieceMap::EMPTY;
+        for (i, slot) in self.as_array().iter().enumerate() {
+            map.as_array_mut()[i ^ 0b111000] = slot.map(Piece::flip_color);
+        }
+        map
+    }
+
     /// Clears the map, removing all pieces.
     #[inline]
     pub fn clear(&mut self) {
@@ -637,10 +697,52 @@ impl PieceMap {
         })
     }
 
+    /// Returns the squares containing `piece`.
+    ///
+    /// The result is a [`BitBoard`], which can be iterated over as each of its
+    /// squares.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::board::PieceMap;
+    /// # use hexe_core::prelude::*;
+    /// let map = PieceMap::STANDARD;
+    /// let pawns = map.squares(Piece::WhitePawn);
+    ///
+    /// assert_eq!(pawns, Rank::Two.into());
+    /// ```
+    ///
+    /// [`BitBoard`]: ../struct.BitBoard.html
+    #[inline]
+    pub fn squares(&self, piece: Piece) -> BitBoard {
+        let piece = piece as u8;
+        self.bits_where(|byte| byte == piece)
+    }
+
+    /// Returns an iterator over the squares and pieces of `color`.
+    #[inline]
+    pub fn iter_color<'a>(&'a self, color: Color) -> IterColor<'a> {
+        let color = color as u8;
+        let bits  = self.bits_where(|byte| byte != NONE && byte & 1 == color);
+        IterColor { map: self, bits }
+    }
+
+    /// Returns the squares whose raw bytes satisfy `f`.
+    #[inline]
+    fn bits_where<F: Fn(u8) -> bool>(&self, f: F) -> BitBoard {
+        let bits = self.as_bytes().iter().enumerate().fold(0, |bits, (i, &byte)| {
+            bits | ((f(byte) as u64) << i)
+        });
+        BitBoard(bits)
+    }
+
     /// Gets the given square's corresponding entry in the map for in-place
     /// manipulation.
     #[inline]
-    pub fn entry(&mut self, sq: Square) -> Entry {
+    pub fn entry<'a>(&'a mut self, sq: Square) -> Entry<'a> {
         Entry::from_map(self, sq)
     }
 
@@ -800,11 +902,11 @@ impl PieceMap {
 
     /// Returns an iterator visiting all square-piece pairs in order.
     #[inline]
-    pub fn iter(&self) -> Iter { self.into_iter() }
+    pub fn iter<'a>(&'a self) -> Iter<'a> { self.into_iter() }
 
     /// Returns an iterator visiting all square-piece pairs mutably in order.
     #[inline]
-    pub fn iter_mut(&mut self) -> IterMut { self.into_iter() }
+    pub fn iter_mut<'a>(&'a mut self) -> IterMut<'a> { self.into_iter() }
 
     /// Returns a view into the map as an array of `Option<Piece>`.
     #[inline]
@@ -1,5 +1,6 @@
 use super::*;
 use prelude::*;
+use rand::{Rng, thread_rng};
 
 /// Asserts at compile-time that the piece is less than NONE.
 macro_rules! assert_valid_none {
@@ -94,6 +95,23 @@ fn rank_contains() {
     }
 }
 
+#[test]
+fn from_multi_board() {
+    assert!(PieceMap::from(&MultiBoard::STANDARD) == PieceMap::STANDARD);
+    assert!(PieceMap::from(&MultiBoard::default()) == PieceMap::EMPTY);
+
+    let mut rng = thread_rng();
+    for _ in 0..64 {
+        let mut map = PieceMap::new();
+        for square in Square::ALL {
+            if rng.gen() {
+                map.insert(square, rng.gen());
+            }
+        }
+        assert!(PieceMap::from(&MultiBoard::from(&map)) == map);
+    }
+}
+
 #[test]
 fn is_empty() {
     let mut map = PieceMap::new();
@@ -106,6 +124,38 @@ fn is_empty() {
     assert!(!map.is_empty());
 }
 
+#[test]
+fn entry() {
+    let mut map = PieceMap::new();
+
+    // Inserting into an empty square
+    match map.entry(Square::D4) {
+        Entry::Vacant(entry) => assert_eq!(*entry.insert(Piece::WhiteKnight), Piece::WhiteKnight),
+        Entry::Occupied(_) => panic!("D4 should be vacant"),
+    }
+    assert_eq!(map.get(Square::D4), Some(&Piece::WhiteKnight));
+    assert_eq!(*map.entry(Square::D4).or_insert(Piece::BlackQueen), Piece::WhiteKnight);
+
+    // Modifying an occupied square
+    map.entry(Square::D4).and_modify(|pc| *pc = pc.flip_color());
+    assert_eq!(map.get(Square::D4), Some(&Piece::BlackKnight));
+    match map.entry(Square::D4) {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.insert(Piece::BlackBishop), Piece::BlackKnight);
+            assert_eq!(*entry.get(), Piece::BlackBishop);
+        },
+        Entry::Vacant(_) => panic!("D4 should be occupied"),
+    }
+
+    // Modifying does nothing to an empty square
+    map.entry(Square::E5).and_modify(|pc| *pc = Piece::WhiteKing);
+    assert_eq!(map.get(Square::E5), None);
+
+    assert_eq!(map.entry(Square::D4).remove(), Some(Piece::BlackBishop));
+    assert_eq!(map.entry(Square::D4).remove(), None);
+    assert!(map.is_empty());
+}
+
 #[test]
 fn role_at() {
     let map = PieceMap::STANDARD;
@@ -231,3 +281,45 @@ fn castle() {
         rook:  F8;
     }
 }
+
+#[test]
+fn squares() {
+    let map = PieceMap::STANDARD;
+    assert_eq!(map.squares(Piece::WhitePawn), Rank::Two.into());
+    assert_eq!(map.squares(Piece::BlackKing), Square::E8.into());
+
+    let mut pawns = map.squares(Piece::WhitePawn);
+    for file in File::ALL {
+        assert_eq!(pawns.next(), Some(Square::new(file, Rank::Two)));
+    }
+    assert_eq!(pawns.next(), None);
+}
+
+#[test]
+fn iter_color() {
+    let map = PieceMap::STANDARD;
+    for color in Color::ALL {
+        let iter = map.iter_color(color);
+        assert_eq!(iter.len(), 16);
+
+        for (square, piece) in iter {
+            assert_eq!(piece.color(), color);
+            assert_eq!(map.get(square), Some(&piece));
+        }
+    }
+}
+
+#[test]
+fn mirror() {
+    assert_eq!(PieceMap::STANDARD.mirror(), PieceMap::STANDARD);
+
+    let mut map = PieceMap::STANDARD;
+    map.relocate(Square::G1, Square::F3);
+    map.relocate(Square::E7, Square::E5);
+
+    let mirror = map.mirror();
+    assert_ne!(mirror, map);
+    assert_eq!(mirror.get(Square::F6), Some(&Piece::BlackKnight));
+    assert_eq!(mirror.get(Square::E4), Some(&Piece::WhitePawn));
+    assert_eq!(mirror.mirror(), map);
+}
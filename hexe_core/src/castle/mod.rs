@@ -177,6 +177,65 @@ impl Rights {
     /// Black queenside.
     pub const BLACK_QUEEN: Rights = Rights(0b1000);
 
+    /// Returns the rights that differ between `self` and `other`.
+    ///
+    /// This is useful for finding which rights were lost between two states.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// use hexe_core::castle::Right;
+    ///
+    /// let old = Rights::FULL;
+    /// let new = old - Right::WhiteKing;
+    ///
+    /// assert_eq!(old.toggled(new), Rights::WHITE_KING);
+    /// assert!(new.toggled(new).is_empty());
+    /// ```
+    #[inline]
+    pub fn toggled(self, other: Rights) -> Rights {
+        Rights(self.0 ^ other.0)
+    }
+
+    /// Removes both rights of `color` from `self`, such as when its king
+    /// moves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let mut rights = Rights::FULL;
+    /// rights.clear_color(Color::White);
+    ///
+    /// assert_eq!(rights, Rights::BLACK);
+    /// ```
+    #[inline]
+    pub fn clear_color(&mut self, color: Color) {
+        *self -= Rights::from(color);
+    }
+
+    /// Returns an iterator over the color and board side of each right in
+    /// `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// use hexe_core::castle::Side;
+    ///
+    /// let rights = Rights::WHITE_QUEEN | Rights::BLACK_KING;
+    /// let mut iter = rights.iter_decomposed();
+    ///
+    /// assert_eq!(iter.next(), Some((Color::White, Side::Queen)));
+    /// assert_eq!(iter.next(), Some((Color::Black, Side::King)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter_decomposed(&self) -> impl Iterator<Item = (Color, Side)> {
+        self.map(|right| (right.color(), right.side()))
+    }
+
     /// Returns the result of applying a function to a mutable string
     /// representation of `self`.
     #[inline]
@@ -272,6 +331,62 @@ impl Right {
         }
     }
 
+    /// Returns the square from which the king castles for `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Right::WhiteKing.king_origin(), Square::E1);
+    /// assert_eq!(Right::BlackQueen.king_origin(), Square::E8);
+    /// ```
+    #[inline]
+    pub fn king_origin(self) -> Square {
+        TABLES.king[self as usize].0
+    }
+
+    /// Returns the square to which the king castles for `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Right::WhiteKing.king_destination(), Square::G1);
+    /// assert_eq!(Right::BlackQueen.king_destination(), Square::C8);
+    /// ```
+    #[inline]
+    pub fn king_destination(self) -> Square {
+        TABLES.king[self as usize].1
+    }
+
+    /// Returns the square from which the rook castles for `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Right::WhiteKing.rook_origin(), Square::H1);
+    /// assert_eq!(Right::BlackQueen.rook_origin(), Square::A8);
+    /// ```
+    #[inline]
+    pub fn rook_origin(self) -> Square {
+        TABLES.rook[self as usize].0
+    }
+
+    /// Returns the square to which the rook castles for `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Right::WhiteKing.rook_destination(), Square::F1);
+    /// assert_eq!(Right::BlackQueen.rook_destination(), Square::D8);
+    /// ```
+    #[inline]
+    pub fn rook_destination(self) -> Square {
+        TABLES.rook[self as usize].1
+    }
+
     /// Returns the path between the rook and king for this right.
     #[inline]
     pub fn path(self) -> BitBoard {
@@ -363,6 +478,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn all_order() {
+        assert_eq!(Right::ALL.len(), 4);
+        assert_eq!(Side::ALL.len(),  2);
+
+        for (i, right) in Right::ALL.enumerate() {
+            assert_eq!(right as usize, i);
+        }
+        for (i, side) in Side::ALL.enumerate() {
+            assert_eq!(side as usize, i);
+        }
+        assert!(Right::ALL.eq(Rights::FULL));
+    }
+
+    #[test]
+    fn clear_color() {
+        let mut rights = Rights::FULL;
+        rights.clear_color(Color::White);
+        assert_eq!(rights, Rights::BLACK_KING | Rights::BLACK_QUEEN);
+
+        rights.clear_color(Color::White);
+        assert_eq!(rights, Rights::BLACK);
+
+        rights.clear_color(Color::Black);
+        assert!(rights.is_empty());
+
+        for rights in (0..16u8).map(Rights::from) {
+            for color in Color::ALL {
+                let mut cleared = rights;
+                cleared.clear_color(color);
+                assert!(!cleared.intersects(Rights::from(color)));
+                assert_eq!(cleared | (rights & Rights::from(color)), rights);
+            }
+        }
+    }
+
+    #[test]
+    fn toggled() {
+        for rights in (0..16u8).map(Rights::from) {
+            assert!(rights.toggled(rights).is_empty());
+            assert_eq!(rights.toggled(Rights::EMPTY), rights);
+            assert_eq!(rights.toggled(!rights), Rights::FULL);
+
+            for right in rights {
+                let changed = rights.toggled(rights - right);
+                assert_eq!(changed.len(), 1);
+                assert_eq!(changed, Rights::from(right));
+                assert_eq!(changed.into_iter().next(), Some(right));
+            }
+        }
+    }
+
+    #[test]
+    fn iter_decomposed() {
+        let pairs: [(Color, Side); 4] = [
+            (Color::White, Side::King), (Color::White, Side::Queen),
+            (Color::Black, Side::King), (Color::Black, Side::Queen),
+        ];
+        assert!(Rights::FULL.iter_decomposed().eq(pairs.iter().cloned()));
+        assert_eq!(Rights::EMPTY.iter_decomposed().next(), None);
+
+        for (color, side) in Rights::FULL.iter_decomposed() {
+            assert!(Rights::FULL.contains(Right::new(color, side)));
+        }
+    }
+
     #[test]
     fn castle_right_char() {
         for right in Rights::FULL {
@@ -371,6 +552,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn castle_right_squares() {
+        use square::Square::*;
+
+        let squares = [
+            (Right::WhiteKing,  [E1, G1, H1, F1]),
+            (Right::WhiteQueen, [E1, C1, A1, D1]),
+            (Right::BlackKing,  [E8, G8, H8, F8]),
+            (Right::BlackQueen, [E8, C8, A8, D8]),
+        ];
+        for &(right, exp) in &squares {
+            assert_eq!(right.king_origin(),      exp[0]);
+            assert_eq!(right.king_destination(), exp[1]);
+            assert_eq!(right.rook_origin(),      exp[2]);
+            assert_eq!(right.rook_destination(), exp[3]);
+
+            let mv = ::mv::kind::Castle::new(right);
+            assert_eq!((mv.src(), mv.dst()), (exp[0], exp[1]));
+
+            let (king, rook) = right.extract(&TABLES.mb_masks);
+            assert_eq!(BitBoard(*king), exp[0] | exp[1]);
+            assert_eq!(BitBoard(*rook), exp[2] | exp[3]);
+        }
+    }
+
     #[test]
     fn castle_right_path() {
         fn path(right: Right) -> BitBoard {
@@ -21,6 +21,8 @@ pub struct Tables {
     pub chars: [u8; 4],
     pub pm_value: [u32; 4],
     pub pm_pairs: [(Square, Square); 4],
+    pub king: [(Square, Square); 4],
+    pub rook: [(Square, Square); 4],
     pub path: [BitBoard; 4],
     pub path_iter: [Range<Square>; 4],
 }
@@ -46,6 +48,8 @@ pub static TABLES: Tables = Tables {
         quad!(NONE, NONE,      BlackKing, BlackRook),
     ],
     pm_pairs: [(E1, E1), (E1, A1), (E8, E8), (E8, A8)],
+    king: [(E1, G1), (E1, C1), (E8, G8), (E8, C8)],
+    rook: [(H1, F1), (A1, D1), (H8, F8), (A8, D8)],
     path: [
         path::WHITE_KING,
         path::WHITE_QUEEN,
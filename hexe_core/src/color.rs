@@ -44,6 +44,8 @@ pub enum Color {
 
 impl_ord!(Color);
 
+impl_try_from_char!(Color);
+
 static COLORS: [[u8; 5]; 2] = [*b"White", *b"Black"];
 
 #[cfg(any(test, feature = "rand"))]
@@ -124,6 +126,65 @@ impl ops::Not for Color {
 }
 
 impl Color {
+    /// Returns `Black` if `is_black` is `true`, or `White` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::color::Color;
+    /// assert_eq!(Color::from_bool(false), Color::White);
+    /// assert_eq!(Color::from_bool(true),  Color::Black);
+    /// ```
+    #[inline]
+    pub fn from_bool(is_black: bool) -> Color {
+        (is_black as u8).into()
+    }
+
+    const_fn! {
+        /// Returns the opposite of `self`.
+        ///
+        /// This is the same as `!self`, but is a `const fn` on Rust 1.31 and
+        /// later.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use hexe_core::color::Color;
+        /// assert_eq!(Color::White.other(), Color::Black);
+        /// assert_eq!(Color::Black.other(), Color::White);
+        /// ```
+        #[inline]
+        pub fn other(self) -> Color {
+            [Color::Black, Color::White][self as usize]
+        }
+    }
+
+    /// Returns `1` for `White` and `-1` for `Black`.
+    ///
+    /// This is useful for flipping the sign of a score to be from the
+    /// perspective of `self`.
+    #[inline]
+    pub fn sign(self) -> i32 {
+        1 - 2 * (self as i32)
+    }
+
+    /// Returns `white` or `black` depending on `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::color::Color;
+    /// assert_eq!(Color::White.fold("w", "b"), "w");
+    /// assert_eq!(Color::Black.fold("w", "b"), "b");
+    /// ```
+    #[inline]
+    pub fn fold<T>(self, white: T, black: T) -> T {
+        match self {
+            Color::White => white,
+            Color::Black => black,
+        }
+    }
+
     /// Returns a color from the parsed character.
     #[inline]
     pub fn from_char(ch: char) -> Option<Color> {
@@ -185,6 +246,53 @@ mod tests {
             assert_eq!(Color::from_char(ch), Some(color));
         }
     }
+
+    #[cfg(try_from)]
+    #[test]
+    fn try_from_char() {
+        use core::convert::TryFrom;
+        use misc::FromCharError;
+
+        assert_eq!(Color::try_from('w'), Ok(Color::White));
+        assert_eq!(Color::try_from('B'), Ok(Color::Black));
+
+        for &ch in &['x', '1', ' ', '♔'] {
+            assert_eq!(Color::try_from(ch), Err(FromCharError(ch)));
+        }
+    }
+
+    #[test]
+    fn sign() {
+        assert_eq!(Color::White.sign(), 1);
+        assert_eq!(Color::Black.sign(), -1);
+
+        for &color in &[Color::White, Color::Black] {
+            assert_eq!(Color::from_bool(color == Color::Black), color);
+            assert_eq!(color.fold(1, -1), color.sign());
+            assert_eq!((!color).sign(), -color.sign());
+        }
+    }
+
+    #[test]
+    fn other() {
+        assert_eq!(Color::White.other(), Color::Black);
+        assert_eq!(Color::Black.other(), Color::White);
+
+        for &color in &[Color::White, Color::Black] {
+            assert_eq!(color.other(), !color);
+            assert_eq!(color.other().other(), color);
+        }
+    }
+
+    #[cfg(const_fn)]
+    #[test]
+    fn other_const() {
+        const BLACK: Color = Color::White.other();
+        const WHITE: Color = BLACK.other();
+
+        assert_eq!(BLACK, Color::Black);
+        assert_eq!(WHITE, Color::White);
+    }
 }
 
 #[cfg(all(test, nightly))]
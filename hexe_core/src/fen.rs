@@ -5,6 +5,9 @@
 use core::fmt::{self, Write};
 use core::str;
 
+#[cfg(feature = "serde")]
+use serde::*;
+
 use prelude::*;
 use board::PieceMap;
 
@@ -45,7 +48,7 @@ impl fmt::Display for Fen {
 
         if let Some(sq) = self.en_passant {
             let mut buf: [u8; 4] = *b"    ";
-            buf[1] = char::from(sq.file()) as u8;
+            buf[1] = char::from(sq.file()) as u8 | 32;
             buf[2] = char::from(sq.rank()) as u8;
             let string = unsafe { str::from_utf8_unchecked(&buf) };
             f.write_str(string)?;
@@ -60,6 +63,20 @@ impl fmt::Display for Fen {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for Fen {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ser.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Fen {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        <&str>::deserialize(de)?.parse().map_err(de::Error::custom)
+    }
+}
+
 impl Fen {
     /// FEN for the starting position in standard chess. It is equivalent to:
     ///
@@ -90,6 +107,319 @@ impl Fen {
     };
 }
 
+/// A field of a [`Fen`](struct.Fen.html) string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Field {
+    /// The piece placement.
+    Pieces,
+    /// The active color.
+    Color,
+    /// The castling availability.
+    Castling,
+    /// The en passant target square.
+    EnPassant,
+    /// The halfmove clock.
+    Halfmoves,
+    /// The fullmove number.
+    Fullmoves,
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Field::Pieces    => "piece placement",
+            Field::Color     => "active color",
+            Field::Castling  => "castling availability",
+            Field::EnPassant => "en passant target",
+            Field::Halfmoves => "halfmove clock",
+            Field::Fullmoves => "fullmove number",
+        })
+    }
+}
+
+/// The error returned when `Fen::from_str` fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FromStrError {
+    /// The field is missing.
+    Missing(Field),
+    /// The field contains an unexpected character.
+    Invalid(Field, char),
+    /// The rank in the piece placement does not span exactly 8 squares.
+    RankLength(Rank),
+    /// The piece placement does not have exactly 8 ranks.
+    RankCount,
+    /// The number in the field is too large.
+    Overflow(Field),
+    /// There is more input after the fullmove number.
+    Trailing,
+}
+
+impl fmt::Display for FromStrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FromStrError::Missing(field) => {
+                write!(f, "missing {}", field)
+            },
+            FromStrError::Invalid(field, ch) => {
+                write!(f, "invalid character in {}: '{}'", field, ch)
+            },
+            FromStrError::RankLength(rank) => {
+                write!(f, "rank {} in piece placement does not span 8 squares",
+                       char::from(rank))
+            },
+            FromStrError::RankCount => {
+                f.write_str("piece placement does not have 8 ranks")
+            },
+            FromStrError::Overflow(field) => {
+                write!(f, "{} is too large", field)
+            },
+            FromStrError::Trailing => {
+                f.write_str("unexpected input after fullmove number")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for FromStrError {
+    fn description(&self) -> &str {
+        match *self {
+            FromStrError::Missing(_)    => "missing FEN field",
+            FromStrError::Invalid(..)   => "invalid character in FEN field",
+            FromStrError::RankLength(_) => "invalid FEN rank length",
+            FromStrError::RankCount     => "invalid FEN rank count",
+            FromStrError::Overflow(_)   => "FEN number too large",
+            FromStrError::Trailing      => "trailing FEN input",
+        }
+    }
+}
+
+impl FromStrError {
+    /// Returns the kind of error, identifying the field at fault.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe_core::fen::{Fen, FenError};
+    ///
+    /// let err = "8/8/8/8/8/8/8/8 w - e9 0 1".parse::<Fen>().err().unwrap();
+    /// assert_eq!(err.kind(), FenError::BadEnPassant);
+    ///
+    /// let err = "8/8/8/8/8/8/8/8 w - -".parse::<Fen>().err().unwrap();
+    /// assert_eq!(err.kind(), FenError::WrongFieldCount);
+    /// ```
+    pub fn kind(&self) -> FenError {
+        match *self {
+            FromStrError::Missing(_) | FromStrError::Trailing => {
+                FenError::WrongFieldCount
+            },
+            FromStrError::RankLength(_) | FromStrError::RankCount => {
+                FenError::BadPlacement
+            },
+            FromStrError::Invalid(field, _) | FromStrError::Overflow(field) => {
+                FenError::from(field)
+            },
+        }
+    }
+}
+
+/// The kind of a [`FromStrError`](enum.FromStrError.html), as returned by its
+/// `kind` method.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FenError {
+    /// The piece placement is malformed.
+    BadPlacement,
+    /// The active color is not `w` or `b`.
+    BadActiveColor,
+    /// The castling availability is malformed.
+    BadCastling,
+    /// The en passant target is not `-` or a square.
+    BadEnPassant,
+    /// The halfmove clock is not a valid number.
+    BadHalfmove,
+    /// The fullmove number is not a valid number.
+    BadFullmove,
+    /// The string does not have exactly six fields.
+    WrongFieldCount,
+}
+
+impl From<Field> for FenError {
+    #[inline]
+    fn from(field: Field) -> FenError {
+        match field {
+            Field::Pieces    => FenError::BadPlacement,
+            Field::Color     => FenError::BadActiveColor,
+            Field::Castling  => FenError::BadCastling,
+            Field::EnPassant => FenError::BadEnPassant,
+            Field::Halfmoves => FenError::BadHalfmove,
+            Field::Fullmoves => FenError::BadFullmove,
+        }
+    }
+}
+
+impl From<FromStrError> for FenError {
+    #[inline]
+    fn from(err: FromStrError) -> FenError {
+        err.kind()
+    }
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            FenError::BadPlacement    => "bad piece placement",
+            FenError::BadActiveColor  => "bad active color",
+            FenError::BadCastling     => "bad castling availability",
+            FenError::BadEnPassant    => "bad en passant target",
+            FenError::BadHalfmove     => "bad halfmove clock",
+            FenError::BadFullmove     => "bad fullmove number",
+            FenError::WrongFieldCount => "wrong number of fields",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for FenError {
+    fn description(&self) -> &str {
+        "invalid FEN string"
+    }
+}
+
+impl str::FromStr for Fen {
+    type Err = FromStrError;
+
+    fn from_str(s: &str) -> Result<Fen, FromStrError> {
+        let mut fields = s.split_whitespace();
+        let fen = {
+            let mut next = |field| fields.next().ok_or(FromStrError::Missing(field));
+            Fen {
+                pieces:     parse_pieces(next(Field::Pieces)?)?,
+                color:      parse_color(next(Field::Color)?)?,
+                castling:   parse_castling(next(Field::Castling)?)?,
+                en_passant: parse_en_passant(next(Field::EnPassant)?)?,
+                halfmoves:  parse_number(next(Field::Halfmoves)?, Field::Halfmoves)?,
+                fullmoves:  parse_number(next(Field::Fullmoves)?, Field::Fullmoves)?,
+            }
+        };
+
+        if fields.next().is_some() {
+            Err(FromStrError::Trailing)
+        } else {
+            Ok(fen)
+        }
+    }
+}
+
+fn parse_pieces(s: &str) -> Result<PieceMap, FromStrError> {
+    let mut map  = PieceMap::EMPTY;
+    let mut rank = 7u8;
+    let mut file = 0u8;
+
+    for ch in s.chars() {
+        match ch {
+            '/' => {
+                if file != 8 {
+                    return Err(FromStrError::RankLength(rank.into()));
+                } else if rank == 0 {
+                    return Err(FromStrError::RankCount);
+                }
+                file = 0;
+                rank -= 1;
+            },
+            '1'..='8' => {
+                file += ch as u8 - b'0';
+                if file > 8 {
+                    return Err(FromStrError::RankLength(rank.into()));
+                }
+            },
+            _ => if let Some(pc) = Piece::from_char(ch) {
+                if file == 8 {
+                    return Err(FromStrError::RankLength(rank.into()));
+                }
+                map.insert(Square::new(file.into(), rank.into()), pc);
+                file += 1;
+            } else {
+                return Err(FromStrError::Invalid(Field::Pieces, ch));
+            },
+        }
+    }
+
+    if rank != 0 {
+        Err(FromStrError::RankCount)
+    } else if file != 8 {
+        Err(FromStrError::RankLength(rank.into()))
+    } else {
+        Ok(map)
+    }
+}
+
+fn parse_color(s: &str) -> Result<Color, FromStrError> {
+    let mut chars = s.chars();
+    let color = match chars.next() {
+        Some('w') => Color::White,
+        Some('b') => Color::Black,
+        Some(ch)  => return Err(FromStrError::Invalid(Field::Color, ch)),
+        None      => return Err(FromStrError::Missing(Field::Color)),
+    };
+    match chars.next() {
+        Some(ch) => Err(FromStrError::Invalid(Field::Color, ch)),
+        None     => Ok(color),
+    }
+}
+
+fn parse_castling(s: &str) -> Result<Rights, FromStrError> {
+    if s == "-" {
+        return Ok(Rights::EMPTY);
+    }
+    let mut rights = Rights::EMPTY;
+    for ch in s.chars() {
+        match Right::from_char(ch) {
+            Some(right) if !rights.contains(right) => rights |= right,
+            _ => return Err(FromStrError::Invalid(Field::Castling, ch)),
+        }
+    }
+    Ok(rights)
+}
+
+fn parse_en_passant(s: &str) -> Result<Option<Square>, FromStrError> {
+    if s == "-" {
+        return Ok(None);
+    }
+    let invalid = |ch| FromStrError::Invalid(Field::EnPassant, ch);
+    let mut chars = s.chars();
+
+    let file = match chars.next() {
+        Some(ch @ 'a'..='h') => ch as u8 - b'a',
+        Some(ch) => return Err(invalid(ch)),
+        None     => return Err(FromStrError::Missing(Field::EnPassant)),
+    };
+    let rank = match chars.next() {
+        Some(ch @ '1'..='8') => ch as u8 - b'1',
+        Some(ch) => return Err(invalid(ch)),
+        None     => return Err(FromStrError::Missing(Field::EnPassant)),
+    };
+    match chars.next() {
+        Some(ch) => Err(invalid(ch)),
+        None     => Ok(Some(Square::new(file.into(), rank.into()))),
+    }
+}
+
+fn parse_number(s: &str, field: Field) -> Result<u32, FromStrError> {
+    let mut num = 0u32;
+    for ch in s.chars() {
+        let digit = match ch.to_digit(10) {
+            Some(digit) => digit,
+            None => return Err(FromStrError::Invalid(field, ch)),
+        };
+        num = num.checked_mul(10)
+                 .and_then(|n| n.checked_add(digit))
+                 .ok_or(FromStrError::Overflow(field))?;
+    }
+    Ok(num)
+}
+
 #[cfg(test)]
 #[cfg(feature = "std")]
 mod tests {
@@ -106,5 +436,72 @@ mod tests {
             let string = format!("{}", fen);
             assert_eq!(string, exp);
         }
+
+        // The en passant file is written in lowercase
+        let mut fen = Fen::STANDARD;
+        fen.en_passant = Some(Square::E3);
+        assert_eq!(format!("{}", fen),
+                   "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e3 0 1");
+    }
+
+    #[test]
+    fn from_str() {
+        let fens = [
+            (Fen::STANDARD, "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+            (Fen::EMPTY,    "8/8/8/8/8/8/8/8 w - - 0 1"),
+        ];
+
+        for &(ref fen, s) in fens.iter() {
+            assert!(s.parse::<Fen>().unwrap() == *fen);
+        }
+
+        let s = "rnbqkbnr/pp1ppppp/8/2p5/4P3/8/PPPP1PPP/RNBQKBNR w KQkq c6 0 2";
+        let fen: Fen = s.parse().unwrap();
+        assert_eq!(fen.en_passant, Some(Square::C6));
+        assert_eq!(fen.fullmoves, 2);
+        assert_eq!(format!("{}", fen), s);
+    }
+
+    #[test]
+    fn from_str_error() {
+        let errors = [
+            ("8/8/8/8/8/8/8/9 w - - 0 1", "invalid character in piece placement: '9'"),
+            ("8/8/8/8/8/8/8/7 w - - 0 1", "rank 1 in piece placement does not span 8 squares"),
+            ("8/8/8/8/8/8/8 w - - 0 1",   "piece placement does not have 8 ranks"),
+            ("8/8/8/8/8/8/8/8 x - - 0 1", "invalid character in active color: 'x'"),
+            ("8/8/8/8/8/8/8/8 w KX - 0 1", "invalid character in castling availability: 'X'"),
+            ("8/8/8/8/8/8/8/8 w - e9 0 1", "invalid character in en passant target: '9'"),
+            ("8/8/8/8/8/8/8/8 w - - a 1", "invalid character in halfmove clock: 'a'"),
+            ("8/8/8/8/8/8/8/8 w - - 0 99999999999", "fullmove number is too large"),
+            ("8/8/8/8/8/8/8/8 w - - 0", "missing fullmove number"),
+            ("8/8/8/8/8/8/8/8 w - - 0 1 x", "unexpected input after fullmove number"),
+        ];
+
+        for &(s, msg) in errors.iter() {
+            let err = s.parse::<Fen>().err().unwrap();
+            assert_eq!(format!("{}", err), msg);
+        }
+    }
+
+    #[test]
+    fn error_kind() {
+        let errors = [
+            ("8/8/8/8/8/8/8/9 w - - 0 1",           FenError::BadPlacement),
+            ("8/8/8/8/8/8/8 w - - 0 1",             FenError::BadPlacement),
+            ("8/8/8/8/8/8/8/8 x - - 0 1",           FenError::BadActiveColor),
+            ("8/8/8/8/8/8/8/8 w KK - 0 1",          FenError::BadCastling),
+            ("8/8/8/8/8/8/8/8 w - j3 0 1",          FenError::BadEnPassant),
+            ("8/8/8/8/8/8/8/8 w - - -1 1",          FenError::BadHalfmove),
+            ("8/8/8/8/8/8/8/8 w - - 0 99999999999", FenError::BadFullmove),
+            ("8/8/8/8/8/8/8/8 w - - 0",             FenError::WrongFieldCount),
+            ("8/8/8/8/8/8/8/8 w - - 0 1 x",         FenError::WrongFieldCount),
+            ("",                                    FenError::WrongFieldCount),
+        ];
+
+        for &(s, kind) in errors.iter() {
+            let err = s.parse::<Fen>().err().unwrap();
+            assert_eq!(err.kind(), kind, "{:?}", s);
+            assert_eq!(FenError::from(err), kind);
+        }
     }
 }
@@ -168,7 +168,26 @@ macro_rules! impl_bit_set {
             /// ```
             #[inline]
             pub fn len(&self) -> usize {
-                self.0.count_ones() as usize
+                self.len_u32() as usize
+            }
+
+            /// Returns the number of bits set in `self` as a `u32`, avoiding a
+            /// cast in hot code.
+            ///
+            /// This compiles down to `popcnt` under the same conditions as
+            /// [`len`](#method.len).
+            #[inline]
+            pub fn len_u32(&self) -> u32 {
+                self.0.count_ones()
+            }
+
+            /// Returns the number of bits set in `self`.
+            ///
+            /// This is an alias for [`len_u32`](#method.len_u32) that mirrors
+            /// the method of the same name on integers.
+            #[inline]
+            pub fn count_ones(&self) -> u32 {
+                self.len_u32()
             }
 
             /// Returns whether `self` is empty.
@@ -183,6 +202,15 @@ macro_rules! impl_bit_set {
                 self.0 & self.0.wrapping_sub(1) != 0
             }
 
+            /// Returns whether `self` has exactly one bit set.
+            ///
+            /// This is faster than comparing [`len`](#method.len) against 1
+            /// when `popcnt` is unavailable.
+            #[inline]
+            pub fn is_single(&self) -> bool {
+                self.0 != 0 && !self.has_multiple()
+            }
+
             /// Converts `self` into its single bit.
             #[inline]
             pub fn into_bit(mut self) -> Option<$x> {
@@ -214,7 +242,7 @@ macro_rules! impl_bit_set {
                 self.0.trailing_zeros().into_unchecked()
             }
 
-            /// Returns the least significant bit of `self` without checking
+            /// Returns the most significant bit of `self` without checking
             /// whether `self` is empty.
             #[inline]
             pub unsafe fn msb_unchecked(&self) -> $x {
@@ -289,6 +317,31 @@ macro_rules! impl_composition_ops {
     )+ }
 }
 
+/// Defines a function that is `const` on compilers that support `const fn`.
+macro_rules! const_fn {
+    ($(#[$m:meta])* pub fn $f:ident($($args:tt)*) -> $ret:ty $body:block) => {
+        #[cfg(const_fn)]
+        $(#[$m])* pub const fn $f($($args)*) -> $ret $body
+
+        #[cfg(not(const_fn))]
+        $(#[$m])* pub fn $f($($args)*) -> $ret $body
+    }
+}
+
+macro_rules! impl_try_from_char {
+    ($($t:ty)+) => { $(
+        #[cfg(try_from)]
+        impl ::core::convert::TryFrom<char> for $t {
+            type Error = ::misc::FromCharError;
+
+            #[inline]
+            fn try_from(ch: char) -> Result<$t, ::misc::FromCharError> {
+                <$t>::from_char(ch).ok_or(::misc::FromCharError(ch))
+            }
+        }
+    )+ }
+}
+
 macro_rules! define_from_str_error {
     ($t:ty; #[$m:meta] $msg:expr) => {
         #[$m] #[derive(Copy, Clone, Debug, PartialEq, Eq)]
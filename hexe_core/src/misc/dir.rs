@@ -49,6 +49,41 @@ impl Direction {
         Direction::forward(!color)
     }
 
+    /// Returns the direction opposite to `self`.
+    ///
+    /// This is the same as `!self`.
+    #[inline]
+    pub fn opposite(self) -> Direction {
+        !self
+    }
+
+    /// Returns the `(file, rank)` step taken when moving in `self`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe_core::misc::Direction;
+    ///
+    /// assert_eq!(Direction::UpLeft.delta(), (-1, 1));
+    /// assert_eq!(Direction::Down.delta(),   (0, -1));
+    /// ```
+    #[inline]
+    pub fn delta(self) -> (i8, i8) {
+        static DELTAS: [(i8, i8); 8] = [
+            ( 0,  1), // Up
+            ( 1,  0), // Right
+            ( 1,  1), // UpRight
+            ( 1, -1), // DownRight
+            (-1,  1), // UpLeft
+            (-1, -1), // DownLeft
+            (-1,  0), // Left
+            ( 0, -1), // Down
+        ];
+        DELTAS[self as usize]
+    }
+
     /// Swaps the direction on `Color::Black`.
     #[inline]
     pub fn swap(self, color: Color) -> Direction {
@@ -59,6 +94,7 @@ impl Direction {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use iter::All;
 
     #[test]
     fn not() {
@@ -73,6 +109,17 @@ mod tests {
         for &(a, b) in &NOT {
             assert_eq!(a, !b);
             assert_eq!(!a, b);
+            assert_eq!(a.opposite(), b);
+        }
+    }
+
+    #[test]
+    fn delta() {
+        for dir in Direction::ALL {
+            let (f, r) = dir.delta();
+            let (g, s) = dir.opposite().delta();
+            assert_eq!((f, r), (-g, -s));
+            assert_ne!((f, r), (0, 0));
         }
     }
 }
@@ -1,5 +1,7 @@
 //! Miscellaneous traits and types.
 
+use core::fmt;
+
 mod dir;
 pub use self::dir::Direction;
 
@@ -11,3 +13,26 @@ pub trait Contained<T> {
     /// Returns whether `self` is contained in `other`.
     fn contained_in(self, other: T) -> bool;
 }
+
+/// The error returned when converting an invalid character into a type, such
+/// as via `TryFrom<char>`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FromCharError(pub(crate) char);
+
+impl FromCharError {
+    /// Returns the character that failed to convert.
+    #[inline]
+    pub fn char(&self) -> char { self.0 }
+}
+
+impl fmt::Display for FromCharError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid character {:?}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for FromCharError {
+    #[inline]
+    fn description(&self) -> &str { "invalid character" }
+}
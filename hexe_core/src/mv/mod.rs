@@ -1,7 +1,9 @@
 //! A chess move.
 
-use core::fmt;
+use core::{cmp, fmt, str};
 
+#[cfg(feature = "serde")]
+use serde::*;
 use uncon::FromUnchecked;
 
 use color::Color;
@@ -89,7 +91,107 @@ impl fmt::Debug for Move {
     }
 }
 
+/// The error returned when `Move::from_str` fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FromStrError(());
+
+impl fmt::Display for FromStrError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        "failed to parse a string as a move".fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for FromStrError {
+    #[inline]
+    fn description(&self) -> &str { "failed to parse a string as a move" }
+}
+
+impl str::FromStr for Move {
+    type Err = FromStrError;
+
+    /// Parses a move in UCI notation, as with
+    /// [`Move::from_uci`](struct.Move.html#method.from_uci).
+    ///
+    /// Without a position for context, the squares and promotion piece are
+    /// all that can be parsed. Castles and en passant captures are parsed as
+    /// normal moves, so moves that may be either should instead be matched
+    /// against the legal moves of a position.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// use hexe_core::mv::Kind;
+    ///
+    /// let mv: Move = "e2e4".parse().unwrap();
+    /// assert_eq!(mv, Move::normal(Square::E2, Square::E4));
+    ///
+    /// // Parsed as a king move, not as castling
+    /// let mv: Move = "e1g1".parse().unwrap();
+    /// assert_eq!(mv.kind(), Kind::Normal);
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Move, FromStrError> {
+        Move::from_uci(s).ok_or(FromStrError(()))
+    }
+}
+
+/// Serializes as the UCI string of the move for human-readable formats, and
+/// as its packed `u16` otherwise.
+#[cfg(feature = "serde")]
+impl Serialize for Move {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        if ser.is_human_readable() {
+            self.map_uci(|s| ser.serialize_str(s))
+        } else {
+            ser.serialize_u16(self.0)
+        }
+    }
+}
+
+/// Deserializes from the UCI string of the move for human-readable formats, as
+/// parsed by [`Move::from_uci`](struct.Move.html#method.from_uci), and from its
+/// packed `u16` otherwise.
+///
+/// Only the packed form can distinguish castles and en passant captures from
+/// normal moves.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Move {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        if de.is_human_readable() {
+            Move::from_uci(<&str>::deserialize(de)?).ok_or_else(|| {
+                de::Error::custom("failed to parse a string as a UCI move")
+            })
+        } else {
+            u16::deserialize(de).map(Move)
+        }
+    }
+}
+
 impl Move {
+    /// Returns the null move, which passes the turn without moving a piece.
+    ///
+    /// It has an internal value of zero, the same as a normal move from A1 to
+    /// itself. This is never a legal move, so it cannot be confused with one.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::mv::Move;
+    /// assert!(Move::null().is_null());
+    /// assert!(!Move::null().is_real());
+    /// ```
+    #[inline]
+    pub fn null() -> Move {
+        Move(0)
+    }
+
     /// Creates a new `Move` from one square to another.
     #[inline]
     pub fn normal(src: Square, dst: Square) -> Move {
@@ -102,12 +204,52 @@ impl Move {
         kind::Promotion::new(file, color, piece).into()
     }
 
+    /// Attempts to create a new promotion move from `src` to `dst`, which may
+    /// capture onto an adjacent file.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// # use hexe_core::piece::Promotion;
+    /// # use hexe_core::mv;
+    /// let mv = Move::try_promotion(Square::B7, Square::A8, Promotion::Knight).unwrap();
+    /// assert_eq!(mv.kind(), mv::Kind::Promotion);
+    /// assert_eq!(mv.matches().promotion().map(|mv| mv.piece()), Some(Promotion::Knight));
+    ///
+    /// assert!(Move::try_promotion(Square::B6, Square::B7, Promotion::Queen).is_none());
+    /// ```
+    #[inline]
+    pub fn try_promotion(src: Square, dst: Square, piece: piece::Promotion) -> Option<Move> {
+        kind::Promotion::try_new(src, dst, piece).map(Into::into)
+    }
+
     /// Creates a new castle move for `right`.
     #[inline]
     pub fn castle(right: Right) -> Move {
         kind::Castle::from(right).into()
     }
 
+    /// Attempts to create a new castle move from the king's `src` to `dst`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let mv = Move::try_castle(Square::E1, Square::G1);
+    /// assert_eq!(mv, Some(Move::castle(Right::WhiteKing)));
+    ///
+    /// assert!(Move::try_castle(Square::E1, Square::F1).is_none());
+    /// ```
+    #[inline]
+    pub fn try_castle(src: Square, dst: Square) -> Option<Move> {
+        kind::Castle::try_new(src, dst).map(Into::into)
+    }
+
     /// Creates an en passant move from one square to another.
     #[inline]
     pub fn en_passant(src: Square, dst: Square) -> Option<Move> {
@@ -220,10 +362,135 @@ impl Move {
             Kind::EnPassant => kind::EnPassant(self).into(),
         }
     }
+
+    /// Parses a move in the long algebraic notation used by the [UCI][uci]
+    /// protocol, such as `e2e4` or `e7e8q`. The null move is written as `0000`.
+    ///
+    /// UCI only distinguishes castles and en passant captures from normal
+    /// moves in the context of a position. Those are parsed as normal moves.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// # use hexe_core::piece::Promotion;
+    /// assert_eq!(Move::from_uci("g1f3"), Some(Move::normal(Square::G1, Square::F3)));
+    /// assert_eq!(Move::from_uci("a7a8n"),
+    ///            Some(Move::promotion(File::A, Color::White, Promotion::Knight)));
+    /// assert_eq!(Move::from_uci("0000"), Some(Move::null()));
+    /// assert_eq!(Move::from_uci("a7a8k"), None);
+    /// ```
+    ///
+    /// [uci]: http://wbec-ridderkerk.nl/html/UCIProtocol.html
+    pub fn from_uci(s: &str) -> Option<Move> {
+        let bytes = s.as_bytes();
+        if bytes == b"0000" {
+            return Some(Move::null());
+        }
+        if bytes.len() != 4 && bytes.len() != 5 {
+            return None;
+        }
+
+        let src = Square::from_ascii(&bytes[..2])?;
+        let dst = Square::from_ascii(&bytes[2..4])?;
+        match bytes.get(4) {
+            Some(&b) => {
+                let role  = piece::Role::from_char(b as char)?;
+                let piece = piece::Promotion::from_role(role)?;
+                Move::try_promotion(src, dst, piece)
+            },
+            None => Some(Move::normal(src, dst)),
+        }
+    }
+
+    /// Returns the result of applying a function to the [UCI][uci] string
+    /// representation of `self`, without any heap allocation.
+    ///
+    /// Castles are written as the king's move and the null move as `0000`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// # use hexe_core::piece::Promotion;
+    /// let mv = Move::promotion(File::D, Color::Black, Promotion::Queen);
+    /// mv.map_uci(|s| assert_eq!(s, "d2d1q"));
+    ///
+    /// Move::castle(Right::BlackQueen).map_uci(|s| assert_eq!(s, "e8c8"));
+    /// ```
+    ///
+    /// [uci]: http://wbec-ridderkerk.nl/html/UCIProtocol.html
+    pub fn map_uci<T, F: FnOnce(&mut str) -> T>(self, f: F) -> T {
+        let mut buf = *b"00000";
+        let mut len = 4;
+        if !self.is_null() {
+            buf[..2].copy_from_slice(self.src().as_str_lower().as_bytes());
+            buf[2..4].copy_from_slice(self.dst().as_str_lower().as_bytes());
+            if let Matches::Promotion(mv) = self.matches() {
+                buf[4] = mv.piece().into_char_lower() as u8;
+                len = 5;
+            }
+        }
+        unsafe { f(str::from_utf8_unchecked_mut(&mut buf[..len])) }
+    }
+}
+
+/// A move paired with a score, such as for move ordering.
+///
+/// Scored moves are ordered by score, with ties broken by the bits of the
+/// move so that the ordering agrees with equality.
+///
+/// # Examples
+///
+/// Ordering captures by [MVV-LVA]:
+///
+/// ```
+/// # use hexe_core::prelude::*;
+/// # use hexe_core::mv::ScoredMove;
+/// let mut moves = [
+///     ScoredMove { mv: Move::normal(Square::D1, Square::D8), score: 900 - 9 },
+///     ScoredMove { mv: Move::normal(Square::E4, Square::D5), score: 100 - 1 },
+///     ScoredMove { mv: Move::normal(Square::B1, Square::C3), score: 0 },
+/// ];
+/// moves.sort_unstable_by(|a, b| b.cmp(a));
+///
+/// assert_eq!(moves[0].mv, Move::normal(Square::D1, Square::D8));
+/// ```
+///
+/// [MVV-LVA]: https://www.chessprogramming.org/MVV-LVA
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ScoredMove {
+    /// The move being scored.
+    pub mv: Move,
+    /// The score of the move, where greater is better.
+    pub score: i16,
+}
+
+impl From<ScoredMove> for Move {
+    #[inline]
+    fn from(scored: ScoredMove) -> Move { scored.mv }
+}
+
+impl PartialOrd for ScoredMove {
+    #[inline]
+    fn partial_cmp(&self, other: &ScoredMove) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScoredMove {
+    #[inline]
+    fn cmp(&self, other: &ScoredMove) -> cmp::Ordering {
+        self.score.cmp(&other.score).then(self.mv.0.cmp(&other.mv.0))
+    }
 }
 
 /// A chess piece move kind.
-#[derive(PartialEq, Eq, Clone, Copy, Hash, FromUnchecked)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash, FromUnchecked)]
 #[uncon(impl_from, other(u16, u32, u64, usize))]
 #[repr(u8)]
 pub enum Kind {
@@ -237,6 +504,34 @@ pub enum Kind {
     EnPassant,
 }
 
+static KINDS: [&str; 4] = ["Normal", "Castle", "Promotion", "EnPassant"];
+
+impl Kind {
+    /// Converts `self` into a static string.
+    #[inline]
+    pub fn into_str(self) -> &'static str {
+        KINDS[self as usize]
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Kind {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ser.serialize_str(self.into_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Kind {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        let s = <&str>::deserialize(de)?;
+        match KINDS.iter().position(|&kind| kind == s) {
+            Some(index) => Ok(index.into()),
+            None => Err(de::Error::custom("failed to parse a string as a move kind")),
+        }
+    }
+}
+
 /// A `match`-able inner representation `Move`.
 #[derive(Copy, Clone)]
 pub enum Matches {
@@ -455,6 +750,22 @@ pub mod kind {
             Promotion(Move(file | rank | kind!(Promotion) | meta!(piece)))
         }
 
+        /// Attempts to create a new promotion move from `src` to `dst`.
+        ///
+        /// Unlike [`new`](#method.new), this allows for promotions that capture
+        /// onto an adjacent file.
+        #[inline]
+        pub fn try_new(src: Square, dst: Square, piece: piece::Promotion) -> Option<Promotion> {
+            match (src.rank(), dst.rank()) {
+                (Rank::Seven, Rank::Eight) | (Rank::Two, Rank::One) => {},
+                _ => return None,
+            }
+            if src.file().distance(dst.file()) > 1 {
+                return None;
+            }
+            Some(Promotion(Move(base!(src, dst) | kind!(Promotion) | meta!(piece))))
+        }
+
         /// Returns the kind for `self`.
         #[inline]
         pub fn kind(self) -> Kind { Kind::Promotion }
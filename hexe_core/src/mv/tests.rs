@@ -43,3 +43,219 @@ fn promotion() {
         }
     }
 }
+
+#[test]
+fn constructors() {
+    use prelude::*;
+    use piece::Promotion;
+
+    let promotion = |mv: Move| mv.matches().promotion().map(|mv| mv.piece());
+
+    let mv = Move::normal(Square::G1, Square::F3);
+    assert_eq!(mv.kind(), Kind::Normal);
+    assert_eq!(promotion(mv), None);
+
+    for right in Right::ALL {
+        let mv = Move::castle(right);
+        assert_eq!(mv.kind(), Kind::Castle);
+        assert_eq!(promotion(mv), None);
+        assert_eq!(Move::try_castle(right.king_origin(), right.king_destination()), Some(mv));
+    }
+
+    let mv = Move::en_passant(Square::E5, Square::D6).unwrap();
+    assert_eq!(mv.kind(), Kind::EnPassant);
+    assert_eq!((mv.src(), mv.dst()), (Square::E5, Square::D6));
+    assert_eq!(promotion(mv), None);
+
+    for piece in Promotion::ALL {
+        let mv = Move::promotion(File::E, Color::Black, piece);
+        assert_eq!(mv.kind(), Kind::Promotion);
+        assert_eq!(promotion(mv), Some(piece));
+
+        let mv = Move::try_promotion(Square::E7, Square::D8, piece).unwrap();
+        assert_eq!(mv.kind(), Kind::Promotion);
+        assert_eq!((mv.src(), mv.dst()), (Square::E7, Square::D8));
+        assert_eq!(promotion(mv), Some(piece));
+    }
+    assert_eq!(Move::try_promotion(Square::E7, Square::C8, Promotion::Queen), None);
+    assert_eq!(Move::try_promotion(Square::E2, Square::E1, Promotion::Rook).map(Move::kind),
+               Some(Kind::Promotion));
+}
+
+#[test]
+fn sort_by_key() {
+    use prelude::*;
+
+    let mut moves = MoveVec::new();
+    for (src, dst) in Square::ALL.rev().zip(Square::ALL.skip(7).step_by(9)) {
+        moves.push(Move::normal(src, dst));
+    }
+    assert!(moves.len() > 1);
+
+    moves.sort_by_key(|mv| mv.dst() as usize);
+    for pair in moves.windows(2) {
+        assert!(pair[0].dst() < pair[1].dst(), "{:?}", pair);
+    }
+
+    moves.sort_by(|a, b| b.src().cmp(&a.src()));
+    for pair in moves.windows(2) {
+        assert!(pair[0].src() > pair[1].src(), "{:?}", pair);
+    }
+}
+
+#[test]
+fn uci() {
+    use prelude::*;
+    use piece::Promotion;
+
+    let moves = [
+        (Move::null(), "0000"),
+        (Move::normal(Square::E2, Square::E4), "e2e4"),
+        (Move::castle(Right::WhiteKing), "e1g1"),
+        (Move::en_passant(Square::D5, Square::E6).unwrap(), "d5e6"),
+        (Move::promotion(File::H, Color::White, Promotion::Rook), "h7h8r"),
+        (Move::try_promotion(Square::B2, Square::A1, Promotion::Knight).unwrap(), "b2a1n"),
+    ];
+    for &(mv, s) in &moves {
+        mv.map_uci(|uci| assert_eq!(uci, s));
+
+        let parsed = Move::from_uci(s).unwrap();
+        assert!(parsed.squares_eq(mv));
+        match mv.kind() {
+            Kind::Normal | Kind::Promotion => assert_eq!(parsed, mv),
+            Kind::Castle | Kind::EnPassant => assert_eq!(parsed.kind(), Kind::Normal),
+        }
+    }
+
+    for s in &["", "e2", "e2e", "e2e4 ", "e2e9", "e7e8x", "e7e8k", "e2e4q"] {
+        assert_eq!(Move::from_uci(s), None, "{:?}", s);
+    }
+}
+
+#[test]
+fn from_str() {
+    use prelude::*;
+
+    assert_eq!("e2e4".parse(), Ok(Move::normal(Square::E2, Square::E4)));
+    assert_eq!("e7e8q".parse(), Ok(Move::promotion(File::E, Color::White, Promotion::Queen)));
+
+    // No position to tell castles apart from king moves
+    let mv: Move = "e1g1".parse().unwrap();
+    assert_eq!(mv.kind(), Kind::Normal);
+
+    for s in &["", "e2e4 ", "e7e8x"] {
+        assert_eq!(s.parse::<Move>(), Err(FromStrError(())), "{:?}", s);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use prelude::*;
+    use piece::Promotion;
+    use serde::Deserialize;
+    use serde::de::{Deserializer, Visitor};
+    use serde::de::value::{BorrowedStrDeserializer, Error};
+
+    /// Feeds a packed `u16` to a non-human-readable format.
+    struct Packed(u16);
+
+    impl<'de> Deserializer<'de> for Packed {
+        type Error = Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, v: V) -> Result<V::Value, Error> {
+            v.visit_u16(self.0)
+        }
+
+        fn is_human_readable(&self) -> bool { false }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier ignored_any
+        }
+    }
+
+    let moves = [
+        Move::normal(Square::G1, Square::F3),
+        Move::castle(Right::BlackQueen),
+        Move::en_passant(Square::E4, Square::D3).unwrap(),
+        Move::promotion(File::C, Color::Black, Promotion::Bishop),
+    ];
+    for &mv in &moves {
+        assert_eq!(Move::deserialize(Packed(u16::from(mv))), Ok(mv));
+
+        let uci = mv.map_uci(|s| s.to_owned());
+        let de = BorrowedStrDeserializer::<Error>::new(&uci);
+        assert!(Move::deserialize(de).unwrap().squares_eq(mv));
+    }
+
+    let de = BorrowedStrDeserializer::<Error>::new("e9e4");
+    assert!(Move::deserialize(de).is_err());
+
+    for &kind in &[Kind::Normal, Kind::Castle, Kind::Promotion, Kind::EnPassant] {
+        let de = BorrowedStrDeserializer::<Error>::new(kind.into_str());
+        assert_eq!(Kind::deserialize(de), Ok(kind));
+    }
+    for piece in Promotion::ALL {
+        let de = BorrowedStrDeserializer::<Error>::new(piece.into_str());
+        assert_eq!(Promotion::deserialize(de), Ok(piece));
+    }
+    let de = BorrowedStrDeserializer::<Error>::new("King");
+    assert!(Promotion::deserialize(de).is_err());
+}
+
+#[test]
+fn scored() {
+    use prelude::*;
+
+    let mut vec = MoveVec::new();
+    vec.push(Move::normal(Square::B1, Square::C3));
+    vec.push(Move::normal(Square::E4, Square::D5));
+    vec.push(Move::normal(Square::D1, Square::D8));
+    vec.push(Move::normal(Square::G1, Square::F3));
+
+    // Victim value minus attacker value, with quiet moves scored 0
+    let mut moves = [ScoredMove { mv: Move::null(), score: 0 }; 4];
+    let scores = vec.scored(|mv| match mv.dst() {
+        Square::D5 => 100 - 1,
+        Square::D8 => 900 - 9,
+        _ => 0,
+    });
+    for (slot, scored) in moves.iter_mut().zip(scores) {
+        *slot = scored;
+    }
+
+    moves.sort_unstable_by(|a, b| b.cmp(a));
+    assert_eq!(moves[0].mv, Move::normal(Square::D1, Square::D8));
+    assert_eq!(moves[1].mv, Move::normal(Square::E4, Square::D5));
+    for pair in moves.windows(2) {
+        assert!(pair[0] >= pair[1]);
+    }
+
+    // Equal scores are ordered by move and differ unless the moves are equal
+    assert!(moves[2] != moves[3]);
+    assert_eq!(moves[2].score, moves[3].score);
+    assert_eq!(Move::from(moves[2]), moves[2].mv);
+}
+
+#[test]
+fn try_push() {
+    use prelude::*;
+
+    let mv = Move::normal(Square::A2, Square::A4);
+    let mut vec = MoveVec::new();
+    for _ in 0..MoveVec::MAX_LEN {
+        assert_eq!(vec.try_push(mv), Ok(()));
+    }
+    assert_eq!(vec.len(), 255);
+
+    let last = Move::normal(Square::H7, Square::H5);
+    assert_eq!(vec.try_push(last), Err(CapacityError(last)));
+    assert_eq!(vec.push(last), Some(last));
+    assert_eq!(vec.len(), 255);
+
+    vec.pop();
+    assert_eq!(vec.try_push(last), Ok(()));
+    assert_eq!(vec.last(), Some(&last));
+}
@@ -2,7 +2,7 @@
 
 use super::*;
 use uncon::*;
-use core::{cmp, mem, ops, ptr, u8};
+use core::{cmp, fmt, mem, ops, ptr, u8};
 use core::borrow::{Borrow, BorrowMut};
 
 const VEC_CAP: usize = MoveVec::MAX_LEN;
@@ -26,6 +26,30 @@ pub struct MoveVec {
     len: u8,
 }
 
+/// The error returned when pushing onto a full [`MoveVec`].
+///
+/// [`MoveVec`]: struct.MoveVec.html
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CapacityError(pub(crate) Move);
+
+impl CapacityError {
+    /// Returns the move that could not be pushed.
+    #[inline]
+    pub fn into_move(self) -> Move { self.0 }
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "move vector is full; cannot push {:?}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for CapacityError {
+    #[inline]
+    fn description(&self) -> &str { "move vector is full" }
+}
+
 impl<T: ?Sized + AsRef<[Move]>> PartialEq<T> for MoveVec {
     #[inline]
     fn eq(&self, other: &T) -> bool {
@@ -178,6 +202,28 @@ impl MoveVec {
         }
     }
 
+    /// Pushes a new move onto the end of the vector, or returns an error
+    /// containing it if full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// # use hexe_core::mv::MoveVec;
+    /// let mv = Move::normal(Square::E2, Square::E4);
+    /// let mut moves = MoveVec::from_elem(mv, MoveVec::MAX_LEN - 1);
+    ///
+    /// assert!(moves.try_push(mv).is_ok());
+    /// assert_eq!(moves.try_push(mv).unwrap_err().into_move(), mv);
+    /// ```
+    #[inline]
+    pub fn try_push(&mut self, mv: Move) -> Result<(), CapacityError> {
+        match self.push(mv) {
+            Some(mv) => Err(CapacityError(mv)),
+            None => Ok(()),
+        }
+    }
+
     /// Pushes a new move onto the end of the vector. Swaps out the last move
     /// and returns it if full.
     #[inline]
@@ -189,8 +235,13 @@ impl MoveVec {
 
     /// Pushes a new move onto the end of the vector without checking whether
     /// it is full.
+    ///
+    /// # Safety
+    ///
+    /// The vector must not be full. This is checked in debug builds.
     #[inline]
     pub unsafe fn push_unchecked(&mut self, mv: Move) {
+        debug_assert!(self.len < u8::MAX, "pushed onto a full MoveVec");
         ptr::write(self.buf.get_unchecked_mut(self.len as usize), mv.0);
         self.len = self.len.wrapping_add(1);
     }
@@ -255,4 +306,60 @@ impl MoveVec {
     /// Equivalent to `&mut vec[..]`.
     #[inline]
     pub fn as_mut_slice(&mut self) -> &mut [Move] { self }
+
+    /// Sorts the vector with the key extraction function `f`.
+    ///
+    /// This sort is unstable (i.e. may reorder equal moves) and does not
+    /// allocate.
+    ///
+    /// # Examples
+    ///
+    /// Ordering moves by destination square:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// # use hexe_core::mv::MoveVec;
+    /// let mut moves = MoveVec::new();
+    /// moves.push(Move::normal(Square::A1, Square::H8));
+    /// moves.push(Move::normal(Square::B1, Square::C3));
+    ///
+    /// moves.sort_by_key(|mv| mv.dst());
+    /// assert_eq!(moves[0], Move::normal(Square::B1, Square::C3));
+    /// ```
+    #[inline]
+    pub fn sort_by_key<K: Ord, F: FnMut(Move) -> K>(&mut self, mut f: F) {
+        self.as_mut_slice().sort_unstable_by_key(|&mv| f(mv))
+    }
+
+    /// Returns an iterator over the moves of `self` paired with their scores,
+    /// as given by `score`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// # use hexe_core::mv::MoveVec;
+    /// let mut moves = MoveVec::new();
+    /// moves.push(Move::normal(Square::E2, Square::E4));
+    ///
+    /// let best = moves.scored(|mv| mv.dst() as i16).max().unwrap();
+    /// assert_eq!(best.score, Square::E4 as i16);
+    /// ```
+    #[inline]
+    pub fn scored<'a, F>(&'a self, mut score: F) -> impl Iterator<Item = ScoredMove> + 'a
+        where F: FnMut(Move) -> i16 + 'a
+    {
+        self.iter().map(move |&mv| ScoredMove { mv, score: score(mv) })
+    }
+
+    /// Sorts the vector with the comparator function `f`.
+    ///
+    /// This sort is unstable (i.e. may reorder equal moves) and does not
+    /// allocate.
+    #[inline]
+    pub fn sort_by<F>(&mut self, mut f: F)
+        where F: FnMut(Move, Move) -> cmp::Ordering
+    {
+        self.as_mut_slice().sort_unstable_by(|&a, &b| f(a, b))
+    }
 }
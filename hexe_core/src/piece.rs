@@ -10,6 +10,8 @@ use prelude::{Color, Extract};
 
 impl_rand!(u8 => Piece, Role, Promotion);
 
+impl_try_from_char!(Piece Role);
+
 /// A chess piece with a role and color.
 #[derive(Copy, Clone, Hash, PartialEq, Eq, FromUnchecked)]
 #[uncon(impl_from, other(u16, u32, u64, usize))]
@@ -43,6 +45,15 @@ pub enum Piece {
 
 static PIECE_CHARS_ASCII: [u8; 12] = *b"PpNnBbRrQqKk";
 
+static PIECE_CHARS_UNICODE: [char; 12] = [
+    '\u{2659}', '\u{265F}', // Pawn
+    '\u{2658}', '\u{265E}', // Knight
+    '\u{2657}', '\u{265D}', // Bishop
+    '\u{2656}', '\u{265C}', // Rook
+    '\u{2655}', '\u{265B}', // Queen
+    '\u{2654}', '\u{265A}', // King
+];
+
 impl From<Piece> for char {
     #[inline]
     fn from(p: Piece) -> char {
@@ -91,11 +102,39 @@ impl Piece {
         (1 & self as u8).into()
     }
 
+    /// Returns `self` with the same role but the opposite color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::piece::Piece;
+    /// assert_eq!(Piece::WhiteKnight.flip_color(), Piece::BlackKnight);
+    /// assert_eq!(Piece::BlackQueen.flip_color(),  Piece::WhiteQueen);
+    /// ```
+    #[inline]
+    pub fn flip_color(self) -> Piece {
+        unsafe { Piece::from_unchecked(self as u8 ^ 1) }
+    }
+
     /// Converts `self` into a character.
     #[inline]
     pub fn into_char(self) -> char {
         self.into()
     }
+
+    /// Converts `self` into its Unicode chess symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::piece::Piece;
+    /// assert_eq!(Piece::WhiteKing.into_unicode(), '♔');
+    /// assert_eq!(Piece::BlackPawn.into_unicode(), '♟');
+    /// ```
+    #[inline]
+    pub fn into_unicode(self) -> char {
+        PIECE_CHARS_UNICODE[self as usize]
+    }
 }
 
 /// A chess piece role.
@@ -121,6 +160,8 @@ impl_ord!(Role);
 
 static ROLES: [&str; 6] = ["Pawn", "Knight", "Bishop", "Rook", "Queen", "King"];
 
+static VALUES: [u32; 6] = [100, 320, 330, 500, 900, 20_000];
+
 impl fmt::Debug for Role {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -228,6 +269,39 @@ impl Role {
         self.into()
     }
 
+    /// Converts `self` into its Unicode chess symbol for `color`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Role::Knight.into_unicode(Color::White), '♘');
+    /// assert_eq!(Role::Knight.into_unicode(Color::Black), '♞');
+    /// ```
+    #[inline]
+    pub fn into_unicode(self, color: Color) -> char {
+        Piece::new(self, color).into_unicode()
+    }
+
+    /// Returns the material value of `self` in centipawns.
+    ///
+    /// The king is valued greater than all other material combined.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe_core::piece::Role;
+    ///
+    /// assert_eq!(Role::Pawn.value(), 100);
+    /// assert!(Role::Knight.value() < Role::Rook.value());
+    /// ```
+    #[inline]
+    pub fn value(self) -> u32 {
+        *self.extract(&VALUES)
+    }
+
     /// Returns whether `self` is a piece role that can slide across the board.
     #[inline]
     pub fn is_slider(self) -> bool {
@@ -295,6 +369,38 @@ impl Promotion {
     pub fn into_str(self) -> &'static str {
         ROLES[1..][self as usize]
     }
+
+    /// Converts `self` into a lowercase character, as used by UCI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::piece::Promotion;
+    /// assert_eq!(Promotion::Queen.into_char_lower(), 'q');
+    /// assert_eq!(Promotion::Knight.into_char_lower(), 'n');
+    /// ```
+    #[inline]
+    pub fn into_char_lower(self) -> char {
+        PIECE_CHARS_ASCII[((self as usize) + 1) << 1 | 1] as char
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Promotion {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ser.serialize_str(self.into_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Promotion {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        Role::deserialize(de).and_then(|role| {
+            Promotion::from_role(role).ok_or_else(|| {
+                de::Error::custom("failed to parse a string as a promotion")
+            })
+        })
+    }
 }
 
 #[cfg(test)]
@@ -312,6 +418,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn promotion_all() {
+        use iter::All;
+
+        let mut roles = Promotion::ALL.map(Role::from);
+        for &role in &[Role::Knight, Role::Bishop, Role::Rook, Role::Queen] {
+            assert_eq!(roles.next(), Some(role));
+        }
+        assert_eq!(roles.next(), None);
+
+        for prom in Promotion::ALL {
+            let role = Role::from(prom);
+            assert!(role != Role::Pawn && role != Role::King);
+            assert_eq!(Promotion::from_role(role), Some(prom));
+            assert_eq!(prom.into_char_lower(), char::from(prom).to_ascii_lowercase());
+        }
+    }
+
     #[test]
     fn piece_role_char() {
         for (i, &ch) in CHARS.iter().enumerate() {
@@ -320,6 +444,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn piece_unicode() {
+        assert_eq!(Piece::WhiteKing.into_unicode(), '\u{2654}');
+        assert_eq!(Piece::BlackKing.into_unicode(), '\u{265A}');
+
+        for piece in (0..12u8).map(Piece::from) {
+            let (role, color) = (piece.role(), piece.color());
+            let white = 0x2659 - role as u32;
+            let black = white + 6;
+
+            let exp = color.fold(white, black);
+            assert_eq!(piece.into_unicode() as u32, exp);
+            assert_eq!(role.into_unicode(color), piece.into_unicode());
+        }
+    }
+
     #[test]
     fn piece_role_from_str() {
         for role in (0..6u8).map(Role::from) {
@@ -336,4 +476,24 @@ mod tests {
             );
         }
     }
+
+    #[cfg(try_from)]
+    #[test]
+    fn try_from_char() {
+        use core::convert::TryFrom;
+        use misc::FromCharError;
+
+        for piece in (0..12u8).map(Piece::from) {
+            assert_eq!(Piece::try_from(piece.into_char()), Ok(piece));
+        }
+        for (i, &ch) in CHARS.iter().enumerate() {
+            assert_eq!(Role::try_from(ch), Ok(Role::from(i)));
+            assert_eq!(Role::try_from(ch.to_ascii_lowercase()), Ok(Role::from(i)));
+        }
+
+        for &ch in &['x', 'Z', '1', ' '] {
+            assert_eq!(Piece::try_from(ch), Err(FromCharError(ch)));
+            assert_eq!(Role::try_from(ch), Err(FromCharError(ch)));
+        }
+    }
 }
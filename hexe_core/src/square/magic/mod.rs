@@ -4,6 +4,9 @@ use square::Square;
 mod tables;
 pub use self::tables::TABLES;
 
+#[cfg(all(feature = "bmi2", target_arch = "x86_64"))]
+mod pext;
+
 const BISHOP_SHIFT: u8 = 64 - 09;
 const ROOK_SHIFT:   u8 = 64 - 12;
 
@@ -31,12 +34,39 @@ fn attacks(table: &Table, sq: Square, occupied: u64, shift: u8) -> u64 {
     unsafe { table[sq as usize].get(occupied, shift) }
 }
 
+#[cfg_attr(all(feature = "bmi2", target_feature = "bmi2"), allow(dead_code))]
+#[inline]
+fn magic_rook_attacks(sq: Square, occupied: u64) -> u64 {
+    attacks(&TABLES.rook, sq, occupied, ROOK_SHIFT)
+}
+
+#[cfg_attr(all(feature = "bmi2", target_feature = "bmi2"), allow(dead_code))]
+#[inline]
+fn magic_bishop_attacks(sq: Square, occupied: u64) -> u64 {
+    attacks(&TABLES.bishop, sq, occupied, BISHOP_SHIFT)
+}
+
+// Use `PEXT` only when BMI2 is known to be available at compile time
+#[cfg(all(feature = "bmi2", target_arch = "x86_64", target_feature = "bmi2"))]
+#[inline]
+pub fn rook_attacks(sq: Square, occupied: BitBoard) -> BitBoard {
+    unsafe { pext::rook_attacks(sq, occupied.0).into() }
+}
+
+#[cfg(all(feature = "bmi2", target_arch = "x86_64", target_feature = "bmi2"))]
+#[inline]
+pub fn bishop_attacks(sq: Square, occupied: BitBoard) -> BitBoard {
+    unsafe { pext::bishop_attacks(sq, occupied.0).into() }
+}
+
+#[cfg(not(all(feature = "bmi2", target_arch = "x86_64", target_feature = "bmi2")))]
 #[inline]
 pub fn rook_attacks(sq: Square, occupied: BitBoard) -> BitBoard {
-    attacks(&TABLES.rook, sq, occupied.0, ROOK_SHIFT).into()
+    magic_rook_attacks(sq, occupied.0).into()
 }
 
+#[cfg(not(all(feature = "bmi2", target_arch = "x86_64", target_feature = "bmi2")))]
 #[inline]
 pub fn bishop_attacks(sq: Square, occupied: BitBoard) -> BitBoard {
-    attacks(&TABLES.bishop, sq, occupied.0, BISHOP_SHIFT).into()
+    magic_bishop_attacks(sq, occupied.0).into()
 }
@@ -0,0 +1,65 @@
+//! Sliding attack lookups indexed via the BMI2 `PEXT` instruction.
+//!
+//! Tables are generated by the build script, with each square's block sized
+//! to the number of subsets of its occupancy mask.
+
+use core::arch::x86_64::_pext_u64;
+use square::Square;
+
+include!(concat!(env!("OUT_DIR"), "/pext.rs"));
+
+#[inline]
+#[target_feature(enable = "bmi2")]
+unsafe fn attacks(
+    masks: &[u64; 64],
+    offsets: &[usize; 64],
+    table: &[u64],
+    sq: Square,
+    occupied: u64,
+) -> u64 {
+    let sq = sq as usize;
+    let idx = _pext_u64(occupied, masks[sq]) as usize;
+    *table.get_unchecked(offsets[sq] + idx)
+}
+
+/// Returns the rook attacks from `sq`.
+///
+/// Must only be called on a CPU that supports BMI2.
+#[inline]
+#[target_feature(enable = "bmi2")]
+pub unsafe fn rook_attacks(sq: Square, occupied: u64) -> u64 {
+    attacks(&ROOK_MASKS, &ROOK_OFFSETS, &ROOK_ATTACKS, sq, occupied)
+}
+
+/// Returns the bishop attacks from `sq`.
+///
+/// Must only be called on a CPU that supports BMI2.
+#[inline]
+#[target_feature(enable = "bmi2")]
+pub unsafe fn bishop_attacks(sq: Square, occupied: u64) -> u64 {
+    attacks(&BISHOP_MASKS, &BISHOP_OFFSETS, &BISHOP_ATTACKS, sq, occupied)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use super::super::{magic_rook_attacks, magic_bishop_attacks};
+    use rand::{Rng, thread_rng};
+
+    #[test]
+    fn matches_magic() {
+        if !is_x86_feature_detected!("bmi2") {
+            return;
+        }
+
+        let mut rng = thread_rng();
+        for _ in 0..20_000 {
+            let sq: Square = rng.gen();
+            let occupied = rng.gen::<u64>() & rng.gen::<u64>();
+            unsafe {
+                assert_eq!(rook_attacks(sq, occupied), magic_rook_attacks(sq, occupied));
+                assert_eq!(bishop_attacks(sq, occupied), magic_bishop_attacks(sq, occupied));
+            }
+        }
+    }
+}
@@ -41,12 +41,14 @@
 //!
 //! [`Square`]: enum.Square.html
 
-use core::{fmt, ops, str};
+use core::{cmp, fmt, ops, str};
 
 #[cfg(feature = "serde")]
 use serde::*;
 use uncon::*;
 
+use castle;
+use iter::Range;
 use misc::Direction;
 use prelude::*;
 
@@ -60,10 +62,17 @@ mod tests;
 
 mod tables;
 use self::tables::TABLES;
+pub use self::tables::{compute_king_attacks, compute_knight_attacks};
 
 impl_ord!(Square, File, Rank);
+
+static LOWER: &str = "\
+    a1b1c1d1e1f1g1h1a2b2c2d2e2f2g2h2a3b3c3d3e3f3g3h3a4b4c4d4e4f4g4h4\
+    a5b5c5d5e5f5g5h5a6b6c6d6e6f6g6h6a7b7c7d7e7f7g7h7a8b8c8d8e8f8g8h8";
 impl_rand!(u8 => Square, File, Rank);
 
+impl_try_from_char!(File Rank);
+
 /// A square on a chess board.
 #[derive(Copy, Clone, Hash, PartialEq, Eq, FromUnchecked)]
 #[uncon(impl_from, other(u16, u32, u64, usize))]
@@ -109,21 +118,9 @@ define_from_str_error! { Square;
 impl str::FromStr for Square {
     type Err = FromStrError;
 
+    #[inline]
     fn from_str(s: &str) -> Result<Square, FromStrError> {
-        let bytes = s.as_bytes();
-        if bytes.len() != 2 { Err(FromStrError(())) } else {
-            // Gets better optimized as a macro for some strange reason
-            macro_rules! convert {
-                ($lo:expr, $hi:expr, $b:expr) => {
-                    match $b {
-                        $lo...$hi => unsafe { ($b - $lo).into_unchecked() },
-                        _ => return Err(FromStrError(())),
-                    }
-                }
-            }
-            Ok(Square::new(convert!(b'a', b'h', bytes[0] | 32),
-                           convert!(b'1', b'8', bytes[1])))
-        }
+        Square::from_ascii(s.as_bytes()).ok_or(FromStrError(()))
     }
 }
 
@@ -181,16 +178,164 @@ impl Square {
         (((rank as u8) << RANK_SHIFT) | (file as u8)).into()
     }
 
+    /// Parses a `Square` from ASCII bytes, such as `b"e4"`, without requiring
+    /// UTF-8 validation. The file may be either case.
+    ///
+    /// This is the same conversion used by `Square::from_str`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::square::*;
+    /// assert_eq!(Square::from_ascii(b"e4"), Some(Square::E4));
+    /// assert_eq!(Square::from_ascii(b"H8"), Some(Square::H8));
+    /// assert_eq!(Square::from_ascii(b"i1"), None);
+    /// assert_eq!(Square::from_ascii(b"a1 "), None);
+    /// ```
     #[inline]
-    pub(crate) fn between(self, other: Square) -> BitBoard {
+    pub fn from_ascii(bytes: &[u8]) -> Option<Square> {
+        if bytes.len() != 2 { None } else {
+            // Gets better optimized as a macro for some strange reason
+            macro_rules! convert {
+                ($lo:expr, $hi:expr, $b:expr) => {
+                    match $b {
+                        $lo...$hi => unsafe { ($b - $lo).into_unchecked() },
+                        _ => return None,
+                    }
+                }
+            }
+            Some(Square::new(convert!(b'a', b'h', bytes[0] | 32),
+                             convert!(b'1', b'8', bytes[1])))
+        }
+    }
+
+    /// Returns the squares strictly between `self` and `other`.
+    ///
+    /// The endpoints are excluded, and the result is empty if the squares do
+    /// not share a file, rank, or diagonal.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let between = Square::A1.between(Square::A4);
+    ///
+    /// assert_eq!(between, Square::A2 | Square::A3);
+    /// assert!(Square::A1.between(Square::B3).is_empty());
+    /// ```
+    #[inline]
+    pub fn between(self, other: Square) -> BitBoard {
         BitBoard(TABLES.between[self as usize][other as usize])
     }
 
+    /// Returns the squares from `self` to `other`, including both endpoints.
+    ///
+    /// The squares must share a file, rank, or diagonal, otherwise the result
+    /// is empty like with [`between`](#method.between). If they are the same
+    /// square, the result is that square alone.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// let path = Square::A1.between_inclusive(Square::A3);
+    ///
+    /// assert_eq!(path, Square::A1 | Square::A2 | Square::A3);
+    /// assert_eq!(Square::C3.between_inclusive(Square::C3), Square::C3.into());
+    /// assert!(Square::A1.between_inclusive(Square::B3).is_empty());
+    /// ```
     #[inline]
-    pub(crate) fn line(self, other: Square) -> BitBoard {
+    pub fn between_inclusive(self, other: Square) -> BitBoard {
+        if self == other || !self.line(other).is_empty() {
+            self.between(other) | self | other
+        } else {
+            BitBoard::EMPTY
+        }
+    }
+
+    /// Returns the entire file, rank, or diagonal passing through both `self`
+    /// and `other`, spanning the board from edge to edge.
+    ///
+    /// The result is empty if the squares are not aligned.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Square::A2.line(Square::A5), File::A.into());
+    /// assert!(Square::A1.line(Square::B3).is_empty());
+    /// ```
+    #[inline]
+    pub fn line(self, other: Square) -> BitBoard {
         BitBoard(TABLES.line[self as usize][other as usize])
     }
 
+    /// Returns the `BitBoard` for the file of `self`.
+    #[inline]
+    pub fn file_bb(self) -> BitBoard {
+        self.file().into()
+    }
+
+    /// Returns the `BitBoard` for the rank of `self`.
+    #[inline]
+    pub fn rank_bb(self) -> BitBoard {
+        self.rank().into()
+    }
+
+    /// Returns the diagonal passing through `self` in the direction of A1 to
+    /// H8, spanning the board from edge to edge.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Square::A1.diagonal(), Square::A1.line(Square::H8));
+    /// assert_eq!(Square::G1.diagonal(), Square::G1 | Square::H2);
+    /// ```
+    #[inline]
+    pub fn diagonal(self) -> BitBoard {
+        const DIAGONAL: u64 = 0x8040201008040201;
+        let shift = 8 * (self.rank() as i32 - self.file() as i32);
+        if shift >= 0 {
+            BitBoard(DIAGONAL << shift)
+        } else {
+            BitBoard(DIAGONAL >> -shift)
+        }
+    }
+
+    /// Returns the diagonal passing through `self` in the direction of A8 to
+    /// H1, spanning the board from edge to edge.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Square::H1.anti_diagonal(), Square::H1.line(Square::A8));
+    /// assert_eq!(Square::A2.anti_diagonal(), Square::A2 | Square::B1);
+    /// ```
+    #[inline]
+    pub fn anti_diagonal(self) -> BitBoard {
+        const ANTI_DIAGONAL: u64 = 0x0102040810204080;
+        let shift = 8 * (self.rank() as i32 + self.file() as i32 - 7);
+        if shift >= 0 {
+            BitBoard(ANTI_DIAGONAL << shift)
+        } else {
+            BitBoard(ANTI_DIAGONAL >> -shift)
+        }
+    }
+
     /// Returns the `File` for `self`.
     #[inline]
     pub fn file(self) -> File {
@@ -229,6 +374,24 @@ impl Square {
         (RANK_BITS ^ self as u8).into()
     }
 
+    /// Returns `self` from the perspective of `color`.
+    ///
+    /// This is the identity for white and [`rev_rank`](#method.rev_rank) for
+    /// black, which allows for tables written from white's perspective to be
+    /// used for either color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Square::A2.relative(Color::White), Square::A2);
+    /// assert_eq!(Square::A2.relative(Color::Black), Square::A7);
+    /// ```
+    #[inline]
+    pub fn relative(self, color: Color) -> Square {
+        color.fold(self, self.rev_rank())
+    }
+
     /// Returns `self` shifted up one rank, or `None` if at last rank.
     #[inline]
     pub fn up(self) -> Option<Square> {
@@ -259,6 +422,35 @@ impl Square {
         (self as u8).wrapping_sub(RANK_INC).into()
     }
 
+    /// Returns `self` shifted one rank toward `color`'s last rank, or `None`
+    /// if already there.
+    ///
+    /// This is [`up`](#method.up) for white and [`down`](#method.down) for
+    /// black.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Square::E2.forward(Color::White), Some(Square::E3));
+    /// assert_eq!(Square::E7.forward(Color::Black), Some(Square::E6));
+    /// assert_eq!(Square::E1.forward(Color::Black), None);
+    /// ```
+    #[inline]
+    pub fn forward(self, color: Color) -> Option<Square> {
+        match color {
+            Color::White => self.up(),
+            Color::Black => self.down(),
+        }
+    }
+
+    /// Returns `self` shifted one rank toward `color`'s first rank, or `None`
+    /// if already there.
+    #[inline]
+    pub fn backward(self, color: Color) -> Option<Square> {
+        self.forward(!color)
+    }
+
     /// Returns `self` shifted right one file, or `None` if at last file.
     #[inline]
     pub fn right(self) -> Option<Square> {
@@ -298,6 +490,30 @@ impl Square {
         BitBoard::from(self).shift(direction).lsb()
     }
 
+    /// Returns `self` offset by one step in `direction` (relative to white's
+    /// perspective), or `None` if the step leaves the board.
+    ///
+    /// This has the same result as [`shift`](#method.shift) but works on the
+    /// file and rank of `self` directly, as described by
+    /// [`Direction::delta`](../misc/enum.Direction.html#method.delta).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use hexe_core::misc::Direction;
+    /// use hexe_core::prelude::*;
+    ///
+    /// assert_eq!(Square::B2.offset(Direction::DownLeft), Some(Square::A1));
+    /// assert_eq!(Square::A2.offset(Direction::DownLeft), None);
+    /// ```
+    #[inline]
+    pub fn offset(self, direction: Direction) -> Option<Square> {
+        let (df, dr) = direction.delta();
+        Some(Square::new(self.file().offset(df)?, self.rank().offset(dr)?))
+    }
+
     /// Returns `self` shifted in `direction` (relative to white's perspective),
     /// wrapping the result around to the opposite side of the board.
     pub fn wrapping_shift(self, direction: Direction) -> Square {
@@ -353,6 +569,29 @@ impl Square {
         (BLACK >> (self as usize % MOD)).into()
     }
 
+    /// Returns the half of the board that `self` is on, with files A through D
+    /// being the queen side and E through H being the king side.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// use hexe_core::castle::Side;
+    ///
+    /// assert_eq!(Square::G1.board_side(), Side::King);
+    /// assert_eq!(Square::D8.board_side(), Side::Queen);
+    /// ```
+    #[inline]
+    pub fn board_side(self) -> castle::Side {
+        if (self.file() as u8) < (File::E as u8) {
+            castle::Side::Queen
+        } else {
+            castle::Side::King
+        }
+    }
+
     /// Returns whether `self` and `other` are equal in color.
     #[inline]
     pub fn color_eq(self, other: Square) -> bool {
@@ -416,6 +655,28 @@ impl Square {
         TABLES.distance[self as usize][other as usize] as usize
     }
 
+    /// Calculates the minimum number of moves required for a knight to travel
+    /// from `self` to `other`.
+    ///
+    /// # Examples
+    ///
+    /// A knight needs six moves to cross the board between opposite corners:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Square::A1.knight_distance(Square::H8), 6);
+    ///
+    /// for s1 in Square::ALL {
+    ///     for s2 in s1.knight_attacks() {
+    ///         assert_eq!(s1.knight_distance(s2), 1);
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn knight_distance(self, other: Square) -> usize {
+        TABLES.knight_distance[self as usize][other as usize] as usize
+    }
+
     /// Calculates the [Manhattan distance][wiki] between `self` and `other`.
     ///
     /// The result is the distance when strictly taking a horizontal/vertical
@@ -539,6 +800,25 @@ impl Square {
         unsafe { f(str::from_utf8_unchecked_mut(&mut buf)) }
     }
 
+    /// Returns a static lowercase string representation of `self`.
+    ///
+    /// Unlike [`map_str`](#method.map_str), the string is not limited to the
+    /// duration of a closure. This makes it ideal for lightweight logging.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Square::E4.as_str_lower(), "e4");
+    /// ```
+    #[inline]
+    pub fn as_str_lower(self) -> &'static str {
+        let idx = (self as usize) * 2;
+        unsafe { LOWER.get_unchecked(idx..(idx + 2)) }
+    }
+
     /// Returns the attacks for `piece` at `self`, taking `occupied` into
     /// account for sliding pieces.
     pub fn attacks(self, piece: Piece, occupied: BitBoard) -> BitBoard {
@@ -558,6 +838,64 @@ impl Square {
         BitBoard(TABLES.pawns[color as usize][self as usize])
     }
 
+    /// Returns the square that a pawn of `color` moves to when pushed one
+    /// step from `self`, or `None` if the push leaves the board.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Square::E2.pawn_push(Color::White), Some(Square::E3));
+    /// assert_eq!(Square::E2.pawn_push(Color::Black), Some(Square::E1));
+    /// assert_eq!(Square::E8.pawn_push(Color::White), None);
+    /// ```
+    #[inline]
+    pub fn pawn_push(self, color: Color) -> Option<Square> {
+        self.offset(color.fold(Direction::Up, Direction::Down))
+    }
+
+    /// Returns the square that a pawn of `color` moves to when pushed two
+    /// steps from `self`, or `None` if `self` is not on the pawn's starting
+    /// rank.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Square::E2.double_push(Color::White), Some(Square::E4));
+    /// assert_eq!(Square::E7.double_push(Color::Black), Some(Square::E5));
+    /// assert_eq!(Square::E3.double_push(Color::White), None);
+    /// ```
+    #[inline]
+    pub fn double_push(self, color: Color) -> Option<Square> {
+        if self.rank() == color.fold(Rank::Two, Rank::Seven) {
+            self.pawn_push(color)?.pawn_push(color)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the square of the pawn captured when a pawn of `color` moves
+    /// to `self` as the en passant target.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Square::D6.en_passant_capture(Color::White), Square::D5);
+    /// assert_eq!(Square::D3.en_passant_capture(Color::Black), Square::D4);
+    /// ```
+    #[inline]
+    pub fn en_passant_capture(self, color: Color) -> Square {
+        self.wrapping_shift(color.fold(Direction::Down, Direction::Up))
+    }
+
     /// Returns the knight attacks for `self`.
     #[inline]
     pub fn knight_attacks(self) -> BitBoard {
@@ -636,7 +974,7 @@ impl File {
     #[inline]
     pub fn from_char(ch: char) -> Option<File> {
         match 32 | ch as u8 {
-            b @ b'a' ... b'f' => unsafe {
+            b @ b'a' ... b'h' => unsafe {
                 Some((b - b'a').into_unchecked())
             },
             _ => None,
@@ -674,10 +1012,7 @@ impl Rank {
     /// Returns the first rank for `color`.
     #[inline]
     pub fn first(color: Color) -> Rank {
-        match color {
-            Color::White => Rank::One,
-            Color::Black => Rank::Eight,
-        }
+        color.fold(Rank::One, Rank::Eight)
     }
 
     /// Returns the last rank for `color`.
@@ -716,6 +1051,22 @@ impl Rank {
         BitBoard(TABLES.adj_rank[*self as usize])
     }
 
+    /// Returns `self` from the perspective of `color`.
+    ///
+    /// This is the identity for white and `!self` for black.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexe_core::prelude::*;
+    /// assert_eq!(Rank::Two.relative(Color::White), Rank::Two);
+    /// assert_eq!(Rank::Two.relative(Color::Black), Rank::Seven);
+    /// ```
+    #[inline]
+    pub fn relative(self, color: Color) -> Rank {
+        color.fold(self, !self)
+    }
+
     /// Returns the remaining distance for `color` to reach the end of the board
     /// from `self`.
     ///
@@ -780,6 +1131,26 @@ macro_rules! impl_components {
             pub fn distance(self, other: Self) -> usize {
                 (self as isize - other as isize).abs() as usize
             }
+
+            /// Returns `self` moved by `delta` steps, or `None` if the result
+            /// is off the board.
+            #[inline]
+            pub fn offset(self, delta: i8) -> Option<Self> {
+                let val = (self as i8).wrapping_add(delta);
+                if val as u8 > 7 { None } else {
+                    Some((val as u8).into())
+                }
+            }
+
+            /// Returns a range over `from` through `to`, inclusive.
+            ///
+            /// The range is empty if `from` comes after `to`.
+            #[inline]
+            pub fn range(from: Self, to: Self) -> Range<Self> {
+                let start = from as u8;
+                let end   = cmp::max(start, to as u8 + 1);
+                Range { iter: start..end }
+            }
         }
     )+ }
 }
@@ -0,0 +1,14 @@
+// Included by both `build.rs`, which generates the knight and king attack
+// tables from these, and `square::tables`.
+
+/// The file and rank offsets of the squares attacked by a knight.
+const KNIGHT_OFFSETS: [(i8, i8); 8] = [
+    (-2, -1), (-2, 1), (-1, -2), (-1, 2),
+    ( 1, -2), ( 1, 2), ( 2, -1), ( 2, 1),
+];
+
+/// The file and rank offsets of the squares attacked by a king.
+const KING_OFFSETS: [(i8, i8); 8] = [
+    (-1, -1), (-1, 0), (-1, 1), (0, -1),
+    ( 0,  1), ( 1, -1), ( 1, 0), (1,  1),
+];
@@ -1,11 +1,65 @@
 use board::bit_board::masks::*;
+use prelude::*;
 
-// Currently 70.25 KiB in size
+include!("offsets.rs");
+
+fn offset_attacks(sq: Square, offsets: &[(i8, i8)]) -> BitBoard {
+    offsets.iter().fold(BitBoard::EMPTY, |bits, &(df, dr)| {
+        let file = sq.file() as i8 + df;
+        let rank = sq.rank() as i8 + dr;
+        if (file | rank) as u8 > 7 {
+            bits
+        } else {
+            bits | Square::new((file as u8).into(), (rank as u8).into())
+        }
+    })
+}
+
+/// Computes the knight attacks for `sq` by offsetting it in each direction
+/// that a knight can jump, discarding squares that leave the board.
+///
+/// The knight attack table is generated from the same offsets at build time.
+///
+/// # Examples
+///
+/// ```
+/// # use hexe_core::prelude::*;
+/// use hexe_core::square::compute_knight_attacks;
+///
+/// for square in Square::ALL {
+///     assert_eq!(compute_knight_attacks(square), square.knight_attacks());
+/// }
+/// ```
+pub fn compute_knight_attacks(sq: Square) -> BitBoard {
+    offset_attacks(sq, &KNIGHT_OFFSETS)
+}
+
+/// Computes the king attacks for `sq` by offsetting it by one step in each
+/// direction, discarding squares that leave the board.
+///
+/// The king attack table is generated from the same offsets at build time.
+///
+/// # Examples
+///
+/// ```
+/// # use hexe_core::prelude::*;
+/// use hexe_core::square::compute_king_attacks;
+///
+/// for square in Square::ALL {
+///     assert_eq!(compute_king_attacks(square), square.king_attacks());
+/// }
+/// ```
+pub fn compute_king_attacks(sq: Square) -> BitBoard {
+    offset_attacks(sq, &KING_OFFSETS)
+}
+
+// Currently 74.25 KiB in size
 #[repr(align(64))]
 pub struct Tables {
     pub adj_file: [u64; 8],
     pub adj_rank: [u64; 8],
     pub distance: [[u8; 64]; 64],
+    pub knight_distance: [[u8; 64]; 64],
     pub chebyshev: [u8; 64],
     pub manhattan: [u8; 64],
     pub pawns: [[u64; 64]; 2],
@@ -90,14 +144,80 @@ pub static TABLES: Tables = Tables {
         [7,7,7,7,7,7,7,7,6,6,6,6,6,6,6,6,6,5,5,5,5,5,5,5,6,5,4,4,4,4,4,4,6,5,4,3,3,3,3,3,6,5,4,3,2,2,2,2,6,5,4,3,2,1,1,1,6,5,4,3,2,1,0,1],
         [7,7,7,7,7,7,7,7,7,6,6,6,6,6,6,6,7,6,5,5,5,5,5,5,7,6,5,4,4,4,4,4,7,6,5,4,3,3,3,3,7,6,5,4,3,2,2,2,7,6,5,4,3,2,1,1,7,6,5,4,3,2,1,0],
     ],
+    knight_distance: [
+        [0,3,2,3,2,3,4,5,3,4,1,2,3,4,3,4,2,1,4,3,2,3,4,5,3,2,3,2,3,4,3,4,2,3,2,3,4,3,4,5,3,4,3,4,3,4,5,4,4,3,4,3,4,5,4,5,5,4,5,4,5,4,5,6],
+        [3,0,3,2,3,2,3,4,2,3,2,1,2,3,4,3,1,2,1,4,3,2,3,4,2,3,2,3,2,3,4,3,3,2,3,2,3,4,3,4,4,3,4,3,4,3,4,5,3,4,3,4,3,4,5,4,4,5,4,5,4,5,4,5],
+        [2,3,0,3,2,3,2,3,1,2,3,2,1,2,3,4,4,1,2,1,4,3,2,3,3,2,3,2,3,2,3,4,2,3,2,3,2,3,4,3,3,4,3,4,3,4,3,4,4,3,4,3,4,3,4,5,5,4,5,4,5,4,5,4],
+        [3,2,3,0,3,2,3,2,2,1,2,3,2,1,2,3,3,4,1,2,1,4,3,2,2,3,2,3,2,3,2,3,3,2,3,2,3,2,3,4,4,3,4,3,4,3,4,3,3,4,3,4,3,4,3,4,4,5,4,5,4,5,4,5],
+        [2,3,2,3,0,3,2,3,3,2,1,2,3,2,1,2,2,3,4,1,2,1,4,3,3,2,3,2,3,2,3,2,4,3,2,3,2,3,2,3,3,4,3,4,3,4,3,4,4,3,4,3,4,3,4,3,5,4,5,4,5,4,5,4],
+        [3,2,3,2,3,0,3,2,4,3,2,1,2,3,2,1,3,2,3,4,1,2,1,4,4,3,2,3,2,3,2,3,3,4,3,2,3,2,3,2,4,3,4,3,4,3,4,3,5,4,3,4,3,4,3,4,4,5,4,5,4,5,4,5],
+        [4,3,2,3,2,3,0,3,3,4,3,2,1,2,3,2,4,3,2,3,4,1,2,1,3,4,3,2,3,2,3,2,4,3,4,3,2,3,2,3,5,4,3,4,3,4,3,4,4,5,4,3,4,3,4,3,5,4,5,4,5,4,5,4],
+        [5,4,3,2,3,2,3,0,4,3,4,3,2,1,4,3,5,4,3,2,3,4,1,2,4,3,4,3,2,3,2,3,5,4,3,4,3,2,3,2,4,5,4,3,4,3,4,3,5,4,5,4,3,4,3,4,6,5,4,5,4,5,4,5],
+        [3,2,1,2,3,4,3,4,0,3,2,3,2,3,4,5,3,2,1,2,3,4,3,4,2,1,4,3,2,3,4,5,3,2,3,2,3,4,3,4,2,3,2,3,4,3,4,5,3,4,3,4,3,4,5,4,4,3,4,3,4,5,4,5],
+        [4,3,2,1,2,3,4,3,3,0,3,2,3,2,3,4,2,3,2,1,2,3,4,3,1,2,1,4,3,2,3,4,2,3,2,3,2,3,4,3,3,2,3,2,3,4,3,4,4,3,4,3,4,3,4,5,3,4,3,4,3,4,5,4],
+        [1,2,3,2,1,2,3,4,2,3,0,3,2,3,2,3,1,2,3,2,1,2,3,4,4,1,2,1,4,3,2,3,3,2,3,2,3,2,3,4,2,3,2,3,2,3,4,3,3,4,3,4,3,4,3,4,4,3,4,3,4,3,4,5],
+        [2,1,2,3,2,1,2,3,3,2,3,0,3,2,3,2,2,1,2,3,2,1,2,3,3,4,1,2,1,4,3,2,2,3,2,3,2,3,2,3,3,2,3,2,3,2,3,4,4,3,4,3,4,3,4,3,3,4,3,4,3,4,3,4],
+        [3,2,1,2,3,2,1,2,2,3,2,3,0,3,2,3,3,2,1,2,3,2,1,2,2,3,4,1,2,1,4,3,3,2,3,2,3,2,3,2,4,3,2,3,2,3,2,3,3,4,3,4,3,4,3,4,4,3,4,3,4,3,4,3],
+        [4,3,2,1,2,3,2,1,3,2,3,2,3,0,3,2,4,3,2,1,2,3,2,1,3,2,3,4,1,2,1,4,4,3,2,3,2,3,2,3,3,4,3,2,3,2,3,2,4,3,4,3,4,3,4,3,5,4,3,4,3,4,3,4],
+        [3,4,3,2,1,2,3,4,4,3,2,3,2,3,0,3,3,4,3,2,1,2,3,2,4,3,2,3,4,1,2,1,3,4,3,2,3,2,3,2,4,3,4,3,2,3,2,3,5,4,3,4,3,4,3,4,4,5,4,3,4,3,4,3],
+        [4,3,4,3,2,1,2,3,5,4,3,2,3,2,3,0,4,3,4,3,2,1,2,3,5,4,3,2,3,4,1,2,4,3,4,3,2,3,2,3,5,4,3,4,3,2,3,2,4,5,4,3,4,3,4,3,5,4,5,4,3,4,3,4],
+        [2,1,4,3,2,3,4,5,3,2,1,2,3,4,3,4,0,3,2,3,2,3,4,5,3,2,1,2,3,4,3,4,2,1,4,3,2,3,4,5,3,2,3,2,3,4,3,4,2,3,2,3,4,3,4,5,3,4,3,4,3,4,5,4],
+        [1,2,1,4,3,2,3,4,2,3,2,1,2,3,4,3,3,0,3,2,3,2,3,4,2,3,2,1,2,3,4,3,1,2,1,4,3,2,3,4,2,3,2,3,2,3,4,3,3,2,3,2,3,4,3,4,4,3,4,3,4,3,4,5],
+        [4,1,2,1,4,3,2,3,1,2,3,2,1,2,3,4,2,3,0,3,2,3,2,3,1,2,3,2,1,2,3,4,4,1,2,1,4,3,2,3,3,2,3,2,3,2,3,4,2,3,2,3,2,3,4,3,3,4,3,4,3,4,3,4],
+        [3,4,1,2,1,4,3,2,2,1,2,3,2,1,2,3,3,2,3,0,3,2,3,2,2,1,2,3,2,1,2,3,3,4,1,2,1,4,3,2,2,3,2,3,2,3,2,3,3,2,3,2,3,2,3,4,4,3,4,3,4,3,4,3],
+        [2,3,4,1,2,1,4,3,3,2,1,2,3,2,1,2,2,3,2,3,0,3,2,3,3,2,1,2,3,2,1,2,2,3,4,1,2,1,4,3,3,2,3,2,3,2,3,2,4,3,2,3,2,3,2,3,3,4,3,4,3,4,3,4],
+        [3,2,3,4,1,2,1,4,4,3,2,1,2,3,2,1,3,2,3,2,3,0,3,2,4,3,2,1,2,3,2,1,3,2,3,4,1,2,1,4,4,3,2,3,2,3,2,3,3,4,3,2,3,2,3,2,4,3,4,3,4,3,4,3],
+        [4,3,2,3,4,1,2,1,3,4,3,2,1,2,3,2,4,3,2,3,2,3,0,3,3,4,3,2,1,2,3,2,4,3,2,3,4,1,2,1,3,4,3,2,3,2,3,2,4,3,4,3,2,3,2,3,5,4,3,4,3,4,3,4],
+        [5,4,3,2,3,4,1,2,4,3,4,3,2,1,2,3,5,4,3,2,3,2,3,0,4,3,4,3,2,1,2,3,5,4,3,2,3,4,1,2,4,3,4,3,2,3,2,3,5,4,3,4,3,2,3,2,4,5,4,3,4,3,4,3],
+        [3,2,3,2,3,4,3,4,2,1,4,3,2,3,4,5,3,2,1,2,3,4,3,4,0,3,2,3,2,3,4,5,3,2,1,2,3,4,3,4,2,1,4,3,2,3,4,5,3,2,3,2,3,4,3,4,2,3,2,3,4,3,4,5],
+        [2,3,2,3,2,3,4,3,1,2,1,4,3,2,3,4,2,3,2,1,2,3,4,3,3,0,3,2,3,2,3,4,2,3,2,1,2,3,4,3,1,2,1,4,3,2,3,4,2,3,2,3,2,3,4,3,3,2,3,2,3,4,3,4],
+        [3,2,3,2,3,2,3,4,4,1,2,1,4,3,2,3,1,2,3,2,1,2,3,4,2,3,0,3,2,3,2,3,1,2,3,2,1,2,3,4,4,1,2,1,4,3,2,3,3,2,3,2,3,2,3,4,2,3,2,3,2,3,4,3],
+        [2,3,2,3,2,3,2,3,3,4,1,2,1,4,3,2,2,1,2,3,2,1,2,3,3,2,3,0,3,2,3,2,2,1,2,3,2,1,2,3,3,4,1,2,1,4,3,2,2,3,2,3,2,3,2,3,3,2,3,2,3,2,3,4],
+        [3,2,3,2,3,2,3,2,2,3,4,1,2,1,4,3,3,2,1,2,3,2,1,2,2,3,2,3,0,3,2,3,3,2,1,2,3,2,1,2,2,3,4,1,2,1,4,3,3,2,3,2,3,2,3,2,4,3,2,3,2,3,2,3],
+        [4,3,2,3,2,3,2,3,3,2,3,4,1,2,1,4,4,3,2,1,2,3,2,1,3,2,3,2,3,0,3,2,4,3,2,1,2,3,2,1,3,2,3,4,1,2,1,4,4,3,2,3,2,3,2,3,3,4,3,2,3,2,3,2],
+        [3,4,3,2,3,2,3,2,4,3,2,3,4,1,2,1,3,4,3,2,1,2,3,2,4,3,2,3,2,3,0,3,3,4,3,2,1,2,3,2,4,3,2,3,4,1,2,1,3,4,3,2,3,2,3,2,4,3,4,3,2,3,2,3],
+        [4,3,4,3,2,3,2,3,5,4,3,2,3,4,1,2,4,3,4,3,2,1,2,3,5,4,3,2,3,2,3,0,4,3,4,3,2,1,2,3,5,4,3,2,3,4,1,2,4,3,4,3,2,3,2,3,5,4,3,4,3,2,3,2],
+        [2,3,2,3,4,3,4,5,3,2,3,2,3,4,3,4,2,1,4,3,2,3,4,5,3,2,1,2,3,4,3,4,0,3,2,3,2,3,4,5,3,2,1,2,3,4,3,4,2,1,4,3,2,3,4,5,3,2,3,2,3,4,3,4],
+        [3,2,3,2,3,4,3,4,2,3,2,3,2,3,4,3,1,2,1,4,3,2,3,4,2,3,2,1,2,3,4,3,3,0,3,2,3,2,3,4,2,3,2,1,2,3,4,3,1,2,1,4,3,2,3,4,2,3,2,3,2,3,4,3],
+        [2,3,2,3,2,3,4,3,3,2,3,2,3,2,3,4,4,1,2,1,4,3,2,3,1,2,3,2,1,2,3,4,2,3,0,3,2,3,2,3,1,2,3,2,1,2,3,4,4,1,2,1,4,3,2,3,3,2,3,2,3,2,3,4],
+        [3,2,3,2,3,2,3,4,2,3,2,3,2,3,2,3,3,4,1,2,1,4,3,2,2,1,2,3,2,1,2,3,3,2,3,0,3,2,3,2,2,1,2,3,2,1,2,3,3,4,1,2,1,4,3,2,2,3,2,3,2,3,2,3],
+        [4,3,2,3,2,3,2,3,3,2,3,2,3,2,3,2,2,3,4,1,2,1,4,3,3,2,1,2,3,2,1,2,2,3,2,3,0,3,2,3,3,2,1,2,3,2,1,2,2,3,4,1,2,1,4,3,3,2,3,2,3,2,3,2],
+        [3,4,3,2,3,2,3,2,4,3,2,3,2,3,2,3,3,2,3,4,1,2,1,4,4,3,2,1,2,3,2,1,3,2,3,2,3,0,3,2,4,3,2,1,2,3,2,1,3,2,3,4,1,2,1,4,4,3,2,3,2,3,2,3],
+        [4,3,4,3,2,3,2,3,3,4,3,2,3,2,3,2,4,3,2,3,4,1,2,1,3,4,3,2,1,2,3,2,4,3,2,3,2,3,0,3,3,4,3,2,1,2,3,2,4,3,2,3,4,1,2,1,3,4,3,2,3,2,3,2],
+        [5,4,3,4,3,2,3,2,4,3,4,3,2,3,2,3,5,4,3,2,3,4,1,2,4,3,4,3,2,1,2,3,5,4,3,2,3,2,3,0,4,3,4,3,2,1,2,3,5,4,3,2,3,4,1,2,4,3,4,3,2,3,2,3],
+        [3,4,3,4,3,4,5,4,2,3,2,3,4,3,4,5,3,2,3,2,3,4,3,4,2,1,4,3,2,3,4,5,3,2,1,2,3,4,3,4,0,3,2,3,2,3,4,5,3,2,1,2,3,4,3,4,2,1,4,3,2,3,4,5],
+        [4,3,4,3,4,3,4,5,3,2,3,2,3,4,3,4,2,3,2,3,2,3,4,3,1,2,1,4,3,2,3,4,2,3,2,1,2,3,4,3,3,0,3,2,3,2,3,4,2,3,2,1,2,3,4,3,1,2,1,4,3,2,3,4],
+        [3,4,3,4,3,4,3,4,2,3,2,3,2,3,4,3,3,2,3,2,3,2,3,4,4,1,2,1,4,3,2,3,1,2,3,2,1,2,3,4,2,3,0,3,2,3,2,3,1,2,3,2,1,2,3,4,4,1,2,1,4,3,2,3],
+        [4,3,4,3,4,3,4,3,3,2,3,2,3,2,3,4,2,3,2,3,2,3,2,3,3,4,1,2,1,4,3,2,2,1,2,3,2,1,2,3,3,2,3,0,3,2,3,2,2,1,2,3,2,1,2,3,3,4,1,2,1,4,3,2],
+        [3,4,3,4,3,4,3,4,4,3,2,3,2,3,2,3,3,2,3,2,3,2,3,2,2,3,4,1,2,1,4,3,3,2,1,2,3,2,1,2,2,3,2,3,0,3,2,3,3,2,1,2,3,2,1,2,2,3,4,1,2,1,4,3],
+        [4,3,4,3,4,3,4,3,3,4,3,2,3,2,3,2,4,3,2,3,2,3,2,3,3,2,3,4,1,2,1,4,4,3,2,1,2,3,2,1,3,2,3,2,3,0,3,2,4,3,2,1,2,3,2,1,3,2,3,4,1,2,1,4],
+        [5,4,3,4,3,4,3,4,4,3,4,3,2,3,2,3,3,4,3,2,3,2,3,2,4,3,2,3,4,1,2,1,3,4,3,2,1,2,3,2,4,3,2,3,2,3,0,3,3,4,3,2,1,2,3,2,4,3,2,3,4,1,2,1],
+        [4,5,4,3,4,3,4,3,5,4,3,4,3,2,3,2,4,3,4,3,2,3,2,3,5,4,3,2,3,4,1,2,4,3,4,3,2,1,2,3,5,4,3,2,3,2,3,0,4,3,4,3,2,1,2,3,5,4,3,2,3,4,1,2],
+        [4,3,4,3,4,5,4,5,3,4,3,4,3,4,5,4,2,3,2,3,4,3,4,5,3,2,3,2,3,4,3,4,2,1,4,3,2,3,4,5,3,2,1,2,3,4,3,4,0,3,2,3,2,3,4,5,3,2,1,2,3,4,3,4],
+        [3,4,3,4,3,4,5,4,4,3,4,3,4,3,4,5,3,2,3,2,3,4,3,4,2,3,2,3,2,3,4,3,1,2,1,4,3,2,3,4,2,3,2,1,2,3,4,3,3,0,3,2,3,2,3,4,4,3,2,1,2,3,4,3],
+        [4,3,4,3,4,3,4,5,3,4,3,4,3,4,3,4,2,3,2,3,2,3,4,3,3,2,3,2,3,2,3,4,4,1,2,1,4,3,2,3,1,2,3,2,1,2,3,4,2,3,0,3,2,3,2,3,1,2,3,2,1,2,3,4],
+        [3,4,3,4,3,4,3,4,4,3,4,3,4,3,4,3,3,2,3,2,3,2,3,4,2,3,2,3,2,3,2,3,3,4,1,2,1,4,3,2,2,1,2,3,2,1,2,3,3,2,3,0,3,2,3,2,2,1,2,3,2,1,2,3],
+        [4,3,4,3,4,3,4,3,3,4,3,4,3,4,3,4,4,3,2,3,2,3,2,3,3,2,3,2,3,2,3,2,2,3,4,1,2,1,4,3,3,2,1,2,3,2,1,2,2,3,2,3,0,3,2,3,3,2,1,2,3,2,1,2],
+        [5,4,3,4,3,4,3,4,4,3,4,3,4,3,4,3,3,4,3,2,3,2,3,2,4,3,2,3,2,3,2,3,3,2,3,4,1,2,1,4,4,3,2,1,2,3,2,1,3,2,3,2,3,0,3,2,4,3,2,1,2,3,2,1],
+        [4,5,4,3,4,3,4,3,5,4,3,4,3,4,3,4,4,3,4,3,2,3,2,3,3,4,3,2,3,2,3,2,4,3,2,3,4,1,2,1,3,4,3,2,1,2,3,2,4,3,2,3,2,3,0,3,3,4,3,2,1,2,3,4],
+        [5,4,5,4,3,4,3,4,4,5,4,3,4,3,4,3,5,4,3,4,3,2,3,2,4,3,4,3,2,3,2,3,5,4,3,2,3,4,1,2,4,3,4,3,2,1,2,3,5,4,3,2,3,2,3,0,4,3,4,3,2,1,2,3],
+        [5,4,5,4,5,4,5,6,4,3,4,3,4,5,4,5,3,4,3,4,3,4,5,4,2,3,2,3,4,3,4,5,3,2,3,2,3,4,3,4,2,1,4,3,2,3,4,5,3,4,1,2,3,4,3,4,0,3,2,3,2,3,4,5],
+        [4,5,4,5,4,5,4,5,3,4,3,4,3,4,5,4,4,3,4,3,4,3,4,5,3,2,3,2,3,4,3,4,2,3,2,3,2,3,4,3,1,2,1,4,3,2,3,4,2,3,2,1,2,3,4,3,3,0,3,2,3,2,3,4],
+        [5,4,5,4,5,4,5,4,4,3,4,3,4,3,4,5,3,4,3,4,3,4,3,4,2,3,2,3,2,3,4,3,3,2,3,2,3,2,3,4,4,1,2,1,4,3,2,3,1,2,3,2,1,2,3,4,2,3,0,3,2,3,2,3],
+        [4,5,4,5,4,5,4,5,3,4,3,4,3,4,3,4,4,3,4,3,4,3,4,3,3,2,3,2,3,2,3,4,2,3,2,3,2,3,2,3,3,4,1,2,1,4,3,2,2,1,2,3,2,1,2,3,3,2,3,0,3,2,3,2],
+        [5,4,5,4,5,4,5,4,4,3,4,3,4,3,4,3,3,4,3,4,3,4,3,4,4,3,2,3,2,3,2,3,3,2,3,2,3,2,3,2,2,3,4,1,2,1,4,3,3,2,1,2,3,2,1,2,2,3,2,3,0,3,2,3],
+        [4,5,4,5,4,5,4,5,5,4,3,4,3,4,3,4,4,3,4,3,4,3,4,3,3,4,3,2,3,2,3,2,4,3,2,3,2,3,2,3,3,2,3,4,1,2,1,4,4,3,2,1,2,3,2,1,3,2,3,2,3,0,3,2],
+        [5,4,5,4,5,4,5,4,4,5,4,3,4,3,4,3,5,4,3,4,3,4,3,4,4,3,4,3,2,3,2,3,3,4,3,2,3,2,3,2,4,3,2,3,4,1,2,1,3,4,3,2,1,2,3,2,4,3,2,3,2,3,0,3],
+        [6,5,4,5,4,5,4,5,5,4,5,4,3,4,3,4,4,5,4,3,4,3,4,3,5,4,3,4,3,2,3,2,4,3,4,3,2,3,2,3,5,4,3,2,3,4,1,2,4,3,4,3,2,1,4,3,5,4,3,2,3,2,3,0],
+    ],
     chebyshev: [3,3,3,3,3,3,3,3,3,2,2,2,2,2,2,3,3,2,1,1,1,1,2,3,3,2,1,0,0,1,2,3,3,2,1,0,0,1,2,3,3,2,1,1,1,1,2,3,3,2,2,2,2,2,2,3,3,3,3,3,3,3,3,3],
     manhattan: [6,5,4,3,3,4,5,6,5,4,3,2,2,3,4,5,4,3,2,1,1,2,3,4,3,2,1,0,0,1,2,3,3,2,1,0,0,1,2,3,4,3,2,1,1,2,3,4,5,4,3,2,2,3,4,5,6,5,4,3,3,4,5,6],
     pawns: [
         [0x200,0x500,0xA00,0x1400,0x2800,0x5000,0xA000,0x4000,0x20000,0x50000,0xA0000,0x140000,0x280000,0x500000,0xA00000,0x400000,0x2000000,0x5000000,0xA000000,0x14000000,0x28000000,0x50000000,0xA0000000,0x40000000,0x200000000,0x500000000,0xA00000000,0x1400000000,0x2800000000,0x5000000000,0xA000000000,0x4000000000,0x20000000000,0x50000000000,0xA0000000000,0x140000000000,0x280000000000,0x500000000000,0xA00000000000,0x400000000000,0x2000000000000,0x5000000000000,0xA000000000000,0x14000000000000,0x28000000000000,0x50000000000000,0xA0000000000000,0x40000000000000,0x200000000000000,0x500000000000000,0xA00000000000000,0x1400000000000000,0x2800000000000000,0x5000000000000000,0xA000000000000000,0x4000000000000000,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,],
         [0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x5,0xA,0x14,0x28,0x50,0xA0,0x40,0x200,0x500,0xA00,0x1400,0x2800,0x5000,0xA000,0x4000,0x20000,0x50000,0xA0000,0x140000,0x280000,0x500000,0xA00000,0x400000,0x2000000,0x5000000,0xA000000,0x14000000,0x28000000,0x50000000,0xA0000000,0x40000000,0x200000000,0x500000000,0xA00000000,0x1400000000,0x2800000000,0x5000000000,0xA000000000,0x4000000000,0x20000000000,0x50000000000,0xA0000000000,0x140000000000,0x280000000000,0x500000000000,0xA00000000000,0x400000000000,0x2000000000000,0x5000000000000,0xA000000000000,0x14000000000000,0x28000000000000,0x50000000000000,0xA0000000000000,0x40000000000000,],
     ],
-    knight: [0x20400,0x50800,0xA1100,0x142200,0x284400,0x508800,0xA01000,0x402000,0x2040004,0x5080008,0xA110011,0x14220022,0x28440044,0x50880088,0xA0100010,0x40200020,0x204000402,0x508000805,0xA1100110A,0x1422002214,0x2844004428,0x5088008850,0xA0100010A0,0x4020002040,0x20400040200,0x50800080500,0xA1100110A00,0x142200221400,0x284400442800,0x508800885000,0xA0100010A000,0x402000204000,0x2040004020000,0x5080008050000,0xA1100110A0000,0x14220022140000,0x28440044280000,0x50880088500000,0xA0100010A00000,0x40200020400000,0x204000402000000,0x508000805000000,0xA1100110A000000,0x1422002214000000,0x2844004428000000,0x5088008850000000,0xA0100010A0000000,0x4020002040000000,0x400040200000000,0x800080500000000,0x1100110A00000000,0x2200221400000000,0x4400442800000000,0x8800885000000000,0x100010A000000000,0x2000204000000000,0x4020000000000,0x8050000000000,0x110A0000000000,0x22140000000000,0x44280000000000,0x88500000000000,0x10A00000000000,0x20400000000000,],
-    king: [0x302,0x705,0xE0A,0x1C14,0x3828,0x7050,0xE0A0,0xC040,0x30203,0x70507,0xE0A0E,0x1C141C,0x382838,0x705070,0xE0A0E0,0xC040C0,0x3020300,0x7050700,0xE0A0E00,0x1C141C00,0x38283800,0x70507000,0xE0A0E000,0xC040C000,0x302030000,0x705070000,0xE0A0E0000,0x1C141C0000,0x3828380000,0x7050700000,0xE0A0E00000,0xC040C00000,0x30203000000,0x70507000000,0xE0A0E000000,0x1C141C000000,0x382838000000,0x705070000000,0xE0A0E0000000,0xC040C0000000,0x3020300000000,0x7050700000000,0xE0A0E00000000,0x1C141C00000000,0x38283800000000,0x70507000000000,0xE0A0E000000000,0xC040C000000000,0x302030000000000,0x705070000000000,0xE0A0E0000000000,0x1C141C0000000000,0x3828380000000000,0x7050700000000000,0xE0A0E00000000000,0xC040C00000000000,0x203000000000000,0x507000000000000,0xA0E000000000000,0x141C000000000000,0x2838000000000000,0x5070000000000000,0xA0E0000000000000,0x40C0000000000000,],
+    knight: include!(concat!(env!("OUT_DIR"), "/knight.rs")),
+    king: include!(concat!(env!("OUT_DIR"), "/king.rs")),
     between: [
         [0,0,0x2,0x6,0xE,0x1E,0x3E,0x7E,0,0,0,0,0,0,0,0,0x100,0,0x200,0,0,0,0,0,0x10100,0,0,0x40200,0,0,0,0,0x1010100,0,0,0,0x8040200,0,0,0,0x101010100,0,0,0,0,0x1008040200,0,0,0x10101010100,0,0,0,0,0,0x201008040200,0,0x1010101010100,0,0,0,0,0,0,0x40201008040200,],
         [0,0,0,0x4,0xC,0x1C,0x3C,0x7C,0,0,0,0,0,0,0,0,0,0x200,0,0x400,0,0,0,0,0,0x20200,0,0,0x80400,0,0,0,0,0x2020200,0,0,0,0x10080400,0,0,0,0x202020200,0,0,0,0,0x2010080400,0,0,0x20202020200,0,0,0,0,0,0x402010080400,0,0x2020202020200,0,0,0,0,0,0,],
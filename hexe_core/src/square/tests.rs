@@ -45,6 +45,20 @@ sliding_attacks! { rook_attacks bishop_attacks queen_attacks }
 
 jump_attacks! { knight_attacks king_attacks }
 
+#[test]
+fn sliding_attacks_empty() {
+    let exp = (File::A | Rank::One) ^ Square::A1;
+    assert_eq!(Square::A1.rook_attacks(BitBoard::EMPTY), exp);
+
+    for square in Square::ALL {
+        let lines = BitBoard::from(square.file()) ^ square.rank();
+        assert_eq!(square.rook_attacks(BitBoard::EMPTY), lines);
+
+        let exp = BitBoard::from(square).bishop_attacks(BitBoard::FULL);
+        assert_eq!(square.bishop_attacks(BitBoard::EMPTY), exp);
+    }
+}
+
 #[test]
 fn wrapping_shift() {
     // As an optimization, the library methods only affect rank bits
@@ -94,7 +108,7 @@ fn tables_alignment() {
         })+ }
     }
 
-    test!(distance, pawns, knight, king, between, line);
+    test!(distance, knight_distance, pawns, knight, king, between, line);
 }
 
 #[test]
@@ -111,6 +125,34 @@ fn distance() {
     }
 }
 
+#[test]
+fn knight_distance() {
+    for s1 in Square::ALL {
+        assert_eq!(s1.knight_distance(s1), 0);
+
+        // Expand outward one knight move at a time
+        let mut reached  = BitBoard::from(s1);
+        let mut frontier = reached;
+        let mut steps    = 0;
+        while !frontier.is_empty() {
+            steps += 1;
+            frontier = frontier.knight_attacks() & !reached;
+            reached |= frontier;
+            for s2 in frontier {
+                assert_eq!(s1.knight_distance(s2), steps);
+            }
+        }
+        assert_eq!(reached, BitBoard::FULL);
+
+        for s2 in s1.knight_attacks() {
+            assert_eq!(s1.knight_distance(s2), 1);
+        }
+    }
+
+    assert_eq!(Square::A1.knight_distance(Square::H8), 6);
+    assert_eq!(Square::A1.knight_distance(Square::B2), 4);
+}
+
 #[test]
 fn tri_index() {
     for s1 in Square::ALL {
@@ -135,11 +177,13 @@ fn pawn_attacks() {
 
 #[test]
 fn file_from_char() {
-    for ch in b'A'..(b'F' + 1) {
+    for ch in b'A'..(b'H' + 1) {
         for &ch in &[ch, ch | 32] {
             assert!(File::from_char(ch as _).is_some());
         }
     }
+    assert_eq!(File::from_char('h'), Some(File::H));
+    assert_eq!(File::from_char('i'), None);
 }
 
 #[test]
@@ -149,6 +193,25 @@ fn rank_from_char() {
     }
 }
 
+#[cfg(try_from)]
+#[test]
+fn try_from_char() {
+    use core::convert::TryFrom;
+    use misc::FromCharError;
+
+    for file in File::ALL {
+        assert_eq!(File::try_from(char::from(file)), Ok(file));
+    }
+    for rank in Rank::ALL {
+        assert_eq!(Rank::try_from(char::from(rank)), Ok(rank));
+    }
+
+    for &ch in &['i', '0', '9', ' '] {
+        assert_eq!(File::try_from(ch), Err(FromCharError(ch)));
+        assert_eq!(Rank::try_from(ch), Err(FromCharError(ch)));
+    }
+}
+
 #[test]
 fn square_color() {
     for s1 in Square::ALL {
@@ -163,3 +226,238 @@ fn square_color() {
         }
     }
 }
+
+#[test]
+fn as_str_lower() {
+    assert_eq!(Square::E4.as_str_lower(), "e4");
+
+    for square in Square::ALL {
+        square.map_str(|s| {
+            s.make_ascii_lowercase();
+            assert_eq!(s, square.as_str_lower());
+        });
+    }
+}
+
+#[test]
+fn between_inclusive() {
+    assert_eq!(Square::A1.between_inclusive(Square::A3),
+               Square::A1 | Square::A2 | Square::A3);
+
+    for a in Square::ALL {
+        for b in Square::ALL {
+            let path = a.between_inclusive(b);
+            assert_eq!(path, b.between_inclusive(a));
+            if a == b {
+                assert_eq!(path, BitBoard::from(a));
+            } else if a.line(b).is_empty() {
+                assert!(path.is_empty(), "{:?} {:?}", a, b);
+            } else {
+                assert_eq!(path, a.between(b) | a | b);
+                assert_eq!(path.len(), a.distance(b) + 1);
+            }
+        }
+    }
+}
+
+#[test]
+fn from_ascii() {
+    for square in Square::ALL {
+        square.map_str(|s| {
+            assert_eq!(Square::from_ascii(s.as_bytes()), Some(square));
+            assert_eq!(s.parse(), Ok(square));
+        });
+        let lower = square.as_str_lower();
+        assert_eq!(Square::from_ascii(lower.as_bytes()), Some(square));
+        assert_eq!(lower.parse(), Ok(square));
+    }
+
+    for s in &["", "a", "a0", "a9", "i1", "`1", "e44", "4e"] {
+        assert_eq!(Square::from_ascii(s.as_bytes()), None);
+        assert!(s.parse::<Square>().is_err());
+    }
+}
+
+#[test]
+fn offset() {
+    use misc::Direction::*;
+
+    for square in Square::ALL {
+        for dir in Direction::ALL {
+            assert_eq!(square.offset(dir), square.shift(dir));
+        }
+    }
+
+    let edges = [
+        (Rank::Eight.into(), [Up,    UpLeft,   UpRight]),
+        (Rank::One.into(),   [Down,  DownLeft, DownRight]),
+        (File::A.into(),     [Left,  UpLeft,   DownLeft]),
+        (File::H.into(),     [Right, UpRight,  DownRight]),
+    ];
+
+    for &(edge, dirs) in &edges {
+        let edge: BitBoard = edge;
+        for square in edge {
+            for &dir in &dirs {
+                assert_eq!(square.offset(dir), None, "{} {:?}", square, dir);
+            }
+        }
+    }
+}
+
+#[test]
+fn file_rank_offset() {
+    assert_eq!(File::A.offset(-1), None);
+    assert_eq!(File::A.offset(1), Some(File::B));
+    assert_eq!(Rank::Eight.offset(1), None);
+    assert_eq!(Rank::Three.offset(-2), Some(Rank::One));
+    assert_eq!(File::D.offset(i8::max_value()), None);
+    assert_eq!(Rank::Four.offset(i8::min_value()), None);
+
+    for file in File::ALL {
+        for delta in -8..9 {
+            let expected = file as i8 + delta;
+            match file.offset(delta) {
+                Some(other) => assert_eq!(other as i8, expected),
+                None => assert!(expected < 0 || expected > 7),
+            }
+        }
+    }
+}
+
+#[test]
+fn file_rank_range() {
+    let files = [File::C, File::D, File::E, File::F];
+    let mut range = File::range(File::C, File::F);
+    assert_eq!(range.len(), files.len());
+    for &file in &files {
+        assert_eq!(range.next(), Some(file));
+    }
+    assert_eq!(range.next(), None);
+
+    assert!(File::range(File::F, File::C).is_empty());
+    assert!(Rank::range(Rank::One, Rank::Eight).eq(Rank::ALL));
+    assert!(Rank::range(Rank::Four, Rank::Four).eq(Some(Rank::Four)));
+}
+
+#[test]
+fn board_side() {
+    use castle::Side;
+
+    assert_eq!(Square::G1.board_side(), Side::King);
+
+    for square in Square::ALL {
+        let side = square.board_side();
+        assert_eq!(BitBoard::KING_SIDE.contains(square), side == Side::King);
+        assert_eq!(BitBoard::QUEEN_SIDE.contains(square), side == Side::Queen);
+    }
+}
+
+#[test]
+fn en_passant() {
+    use board::PieceMap;
+
+    for color in Color::ALL {
+        for square in Square::ALL {
+            let push = square.pawn_push(color);
+            if let Some(dst) = square.double_push(color) {
+                assert_eq!(push.and_then(|sq| sq.pawn_push(color)), Some(dst));
+                assert_eq!(push.unwrap().en_passant_capture(!color), dst);
+            }
+            if let Some(dst) = push {
+                assert_eq!(dst.en_passant_capture(color), square);
+            }
+        }
+    }
+
+    let mut map = PieceMap::new();
+    map.insert(Square::E5, Piece::WhitePawn);
+    map.insert(Square::D5, Piece::BlackPawn);
+
+    let ep = Square::D6.en_passant_capture(Color::White);
+    assert_eq!(ep, Square::D5);
+    assert_eq!(map.en_passant(Square::E5, Square::D6), Some(Piece::BlackPawn));
+    assert_eq!(map.get(ep), None);
+}
+
+#[test]
+fn relative() {
+    assert_eq!(Square::A2.relative(Color::Black), Square::A7);
+
+    for square in Square::ALL {
+        assert_eq!(square.relative(Color::White), square);
+        assert_eq!(square.relative(Color::Black), square.rev_rank());
+
+        for color in Color::ALL {
+            let rel = square.relative(color);
+            assert_eq!(rel.file(), square.file());
+            assert_eq!(rel.rank(), square.rank().relative(color));
+            assert_eq!(rel.relative(color), square);
+        }
+    }
+}
+
+#[test]
+fn forward_backward() {
+    assert_eq!(Square::E2.forward(Color::White), Some(Square::E3));
+    assert_eq!(Square::E7.forward(Color::Black), Some(Square::E6));
+    assert_eq!(Square::E2.backward(Color::White), Some(Square::E1));
+    assert_eq!(Square::E7.backward(Color::Black), Some(Square::E8));
+
+    for square in Square::ALL {
+        for color in Color::ALL {
+            let forward = square.forward(color);
+            assert_eq!(forward, BitBoard::from(square).advance(color).lsb());
+            assert_eq!(forward.is_none(), square.rank() == Rank::last(color));
+            assert_eq!(square.backward(color), square.forward(!color));
+
+            if let Some(forward) = forward {
+                assert_eq!(forward.backward(color), Some(square));
+            }
+        }
+    }
+}
+
+#[test]
+fn masks() {
+    assert_eq!(Square::A1.diagonal(), Square::A1.line(Square::H8));
+    assert_eq!(Square::A8.anti_diagonal(), Square::A8.line(Square::H1));
+
+    for square in Square::ALL {
+        let (file, rank) = (square.file() as i32, square.rank() as i32);
+        assert_eq!(square.file_bb(), square.file().into());
+        assert_eq!(square.rank_bb(), square.rank().into());
+
+        let diagonal = Square::ALL.filter(|s| {
+            s.file() as i32 - s.rank() as i32 == file - rank
+        }).collect::<BitBoard>();
+        let anti_diagonal = Square::ALL.filter(|s| {
+            s.file() as i32 + s.rank() as i32 == file + rank
+        }).collect::<BitBoard>();
+
+        assert_eq!(square.diagonal(), diagonal, "{}", square);
+        assert_eq!(square.anti_diagonal(), anti_diagonal, "{}", square);
+    }
+}
+
+#[test]
+fn computed_jump_attacks() {
+    fn brute_force<F: Fn(usize, usize) -> bool>(square: Square, f: F) -> BitBoard {
+        Square::ALL.filter(|&other| {
+            let df = (square.file() as isize - other.file() as isize).abs();
+            let dr = (square.rank() as isize - other.rank() as isize).abs();
+            f(df as usize, dr as usize)
+        }).collect()
+    }
+
+    for square in Square::ALL {
+        let knight = brute_force(square, |df, dr| df * dr == 2);
+        let king   = brute_force(square, |df, dr| df <= 1 && dr <= 1 && df + dr > 0);
+
+        assert_eq!(compute_knight_attacks(square), knight);
+        assert_eq!(compute_king_attacks(square),   king);
+
+        assert_eq!(TABLES.knight[square as usize], knight.0);
+        assert_eq!(TABLES.king[square as usize],   king.0);
+    }
+}